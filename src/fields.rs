@@ -0,0 +1,241 @@
+//! Restrict the terms of a query to named fields of the options.
+use crate::matcher::{self, Matcher};
+use crate::menu::Row;
+
+use std::borrow::Cow;
+
+/// The named fields of the options of a [`PickList`](crate::PickList).
+///
+/// A term of the query written as `name:value` only matches the option if
+/// `value` matches the field with that name, e.g. `lang:rust net` matches
+/// options whose `lang` field matches `rust` and whose label matches `net`.
+/// Terms with unknown field names are matched against the label.
+///
+/// Given to a [`PickList`](crate::PickList), the [`Fields`] also complete the
+/// field names in its dropdown while they are being typed.
+#[allow(missing_debug_implementations)]
+pub struct Fields<'a, T> {
+    fields: Vec<(String, Box<dyn Fn(&T) -> Cow<'_, str> + 'a>)>,
+}
+
+impl<'a, T> Fields<'a, T> {
+    /// Creates new [`Fields`] without any field.
+    pub fn new() -> Self {
+        Self { fields: Vec::new() }
+    }
+
+    /// Adds a field with the given name, whose value is extracted from an
+    /// option by the given function.
+    pub fn field(
+        mut self,
+        name: impl Into<String>,
+        value: impl Fn(&T) -> Cow<'_, str> + 'a,
+    ) -> Self {
+        self.fields.push((name.into(), Box::new(value)));
+        self
+    }
+
+    /// Returns the names of the fields.
+    pub fn names(&self) -> impl Iterator<Item = &str> {
+        self.fields.iter().map(|(name, _)| name.as_str())
+    }
+
+    /// Returns the score of the option for the query, or `None` if it does not
+    /// match.
+    ///
+    /// The terms scoped to a field and the remaining terms are each matched
+    /// with the given [`Matcher`], and their scores are added up.
+    pub fn score(&self, matcher: &impl Matcher, query: &str, option: &T) -> Option<f32>
+    where
+        T: ToString,
+    {
+        let mut rest = Vec::new();
+        let mut score = 0.0;
+
+        for term in query.split_whitespace() {
+            match self.scoped(term) {
+                Some((_, "")) => {}
+                Some((value_of, value)) => score += matcher.score(value, &value_of(option))?,
+                None => rest.push(term),
+            }
+        }
+
+        Some(score + matcher.score(&rest.join(" "), &option.to_string())?)
+    }
+
+    /// Filters the options that match the query and sorts them by descending
+    /// score, like [`matcher::filter`](crate::matcher::filter).
+    pub fn filter(&self, matcher: &impl Matcher, query: &str, options: &[T]) -> Vec<T>
+    where
+        T: Clone + ToString,
+    {
+        matcher::rank(options, |option| self.score(matcher, query, option))
+    }
+
+    /// Splits a `name:value` term scoped to a known field.
+    fn scoped<'b>(&self, term: &'b str) -> Option<(&(dyn Fn(&T) -> Cow<'_, str> + 'a), &'b str)> {
+        let (name, value) = term.split_once(':')?;
+
+        self.fields
+            .iter()
+            .find(|(field, _)| field.eq_ignore_ascii_case(name))
+            .map(|(_, value_of)| (value_of.as_ref(), value))
+    }
+
+    /// Computes the [`Row`]s completing the field name that is being typed at
+    /// the end of the query.
+    pub(crate) fn rows(&self, query: &str) -> Vec<Row> {
+        let partial = query.rsplit(char::is_whitespace).next().unwrap_or_default();
+        let start = query.len() - partial.len();
+
+        if partial.is_empty() || partial.contains(':') {
+            return Vec::new();
+        }
+
+        let partial = partial.to_lowercase();
+
+        self.names()
+            .filter(|name| name.to_lowercase().starts_with(&partial))
+            .map(|name| Row::Completion {
+                label: format!("{name}:"),
+                query: format!("{}{name}:", &query[..start]),
+            })
+            .collect()
+    }
+}
+
+impl<'a, T> Default for Fields<'a, T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::matcher::Substring;
+
+    use std::fmt;
+
+    #[derive(Debug, Clone, PartialEq)]
+    struct Package {
+        name: &'static str,
+        lang: &'static str,
+        license: &'static str,
+    }
+
+    impl fmt::Display for Package {
+        fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+            f.write_str(self.name)
+        }
+    }
+
+    const TOKIO: Package = Package {
+        name: "tokio-net",
+        lang: "rust",
+        license: "MIT",
+    };
+
+    const NETTY: Package = Package {
+        name: "netty",
+        lang: "java",
+        license: "Apache-2.0",
+    };
+
+    fn fields() -> Fields<'static, Package> {
+        Fields::new()
+            .field("lang", |package: &Package| Cow::Borrowed(package.lang))
+            .field("license", |package: &Package| {
+                Cow::Borrowed(package.license)
+            })
+    }
+
+    fn completion(label: &str, query: &str) -> Row {
+        Row::Completion {
+            label: label.to_owned(),
+            query: query.to_owned(),
+        }
+    }
+
+    #[test]
+    fn matches_scoped_terms_against_fields() {
+        let fields = fields();
+
+        assert!(fields.score(&Substring, "lang:rust net", &TOKIO).is_some());
+        assert_eq!(fields.score(&Substring, "lang:rust net", &NETTY), None);
+        assert!(fields.score(&Substring, "LANG:java", &NETTY).is_some());
+    }
+
+    #[test]
+    fn adds_up_the_scores_of_every_term() {
+        let fields = fields();
+
+        let label = Substring.score("net", "tokio-net").unwrap();
+        let lang = Substring.score("rust", "rust").unwrap();
+
+        assert_eq!(
+            fields.score(&Substring, "lang:rust net", &TOKIO),
+            Some(label + lang)
+        );
+    }
+
+    #[test]
+    fn skips_scoped_terms_without_a_value() {
+        let fields = fields();
+
+        assert_eq!(
+            fields.score(&Substring, "lang: net", &NETTY),
+            Substring.score("net", "netty")
+        );
+    }
+
+    #[test]
+    fn matches_unknown_fields_against_the_label() {
+        let fields = fields();
+        let scoped = Package {
+            name: "std:net",
+            ..TOKIO
+        };
+
+        assert_eq!(fields.score(&Substring, "std:net", &TOKIO), None);
+        assert!(fields.score(&Substring, "std:net", &scoped).is_some());
+    }
+
+    #[test]
+    fn filters_by_descending_score() {
+        let fields = fields();
+        let options = [NETTY, TOKIO];
+
+        assert_eq!(fields.filter(&Substring, "lang:rust", &options), [TOKIO]);
+        assert_eq!(fields.filter(&Substring, "net", &options), [NETTY, TOKIO]);
+    }
+
+    #[test]
+    fn completes_the_last_word() {
+        let fields = fields();
+
+        assert_eq!(fields.rows("lan"), [completion("lang:", "lang:")]);
+        assert_eq!(
+            fields.rows("net LI"),
+            [completion("license:", "net license:")]
+        );
+        assert_eq!(
+            fields.rows("l"),
+            [
+                completion("lang:", "lang:"),
+                completion("license:", "license:")
+            ]
+        );
+    }
+
+    #[test]
+    fn does_not_complete_values() {
+        let fields = fields();
+
+        assert!(fields.rows("").is_empty());
+        assert!(fields.rows("lang:").is_empty());
+        assert!(fields.rows("lang:ru").is_empty());
+        assert!(fields.rows("lang ").is_empty());
+        assert!(fields.rows("net").is_empty());
+    }
+}
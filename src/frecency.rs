@@ -0,0 +1,226 @@
+//! Rank options by how frequently and recently they were used.
+use crate::matcher::Matcher;
+
+use std::collections::HashMap;
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+/// The usage of options, decaying over time.
+///
+/// Every recorded usage of an option adds `1.0` to its score, which halves
+/// after every [`Frecency::half_life`]. Options are identified by their label.
+///
+/// Combine a [`Frecency`] with any [`Matcher`] through [`Matcher::boosted`] so
+/// frequently picked options float up for ambiguous queries.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Frecency {
+    half_life: Duration,
+    entries: HashMap<String, Entry>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+struct Entry {
+    score: f32,
+    updated_at: u64,
+}
+
+impl Frecency {
+    /// The default half life of a [`Frecency`], which is one week.
+    pub const DEFAULT_HALF_LIFE: Duration = Duration::from_secs(60 * 60 * 24 * 7);
+
+    /// Creates a new [`Frecency`] with the given half life.
+    pub fn new(half_life: Duration) -> Self {
+        Self {
+            half_life,
+            entries: HashMap::new(),
+        }
+    }
+
+    /// Returns the half life of the [`Frecency`].
+    pub fn half_life(&self) -> Duration {
+        self.half_life
+    }
+
+    /// Records the usage of the option with the given label.
+    pub fn record(&mut self, label: impl Into<String>) {
+        let now = now();
+        let half_life = self.half_life;
+
+        let entry = self.entries.entry(label.into()).or_insert(Entry {
+            score: 0.0,
+            updated_at: now,
+        });
+
+        entry.score = entry.decayed(half_life, now) + 1.0;
+        entry.updated_at = now;
+    }
+
+    /// Returns the current, decayed score of the option with the given label.
+    pub fn score(&self, label: &str) -> f32 {
+        self.entries
+            .get(label)
+            .map_or(0.0, |entry| entry.decayed(self.half_life, now()))
+    }
+
+    /// Forgets the usage of the option with the given label.
+    pub fn remove(&mut self, label: &str) {
+        let _ = self.entries.remove(label);
+    }
+
+    /// Forgets the usage of all options.
+    pub fn clear(&mut self) {
+        self.entries.clear();
+    }
+}
+
+impl Default for Frecency {
+    fn default() -> Self {
+        Self::new(Self::DEFAULT_HALF_LIFE)
+    }
+}
+
+impl Entry {
+    fn decayed(&self, half_life: Duration, now: u64) -> f32 {
+        let age = now.saturating_sub(self.updated_at) as f32;
+
+        self.score * 0.5_f32.powf(age / half_life.as_secs_f32().max(1.0))
+    }
+}
+
+fn now() -> u64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map_or(0, |duration| duration.as_secs())
+}
+
+/// A [`Matcher`] whose scores are boosted by a [`Frecency`].
+///
+/// Created with [`Matcher::boosted`].
+#[derive(Debug, Clone)]
+pub struct Boosted<'a, M> {
+    matcher: M,
+    frecency: &'a Frecency,
+    weight: f32,
+}
+
+impl<'a, M: Matcher> Boosted<'a, M> {
+    /// The default weight of the [`Frecency`] score.
+    pub const DEFAULT_WEIGHT: f32 = 0.5;
+
+    /// Boosts the scores of the given [`Matcher`] with a [`Frecency`].
+    pub fn new(matcher: M, frecency: &'a Frecency) -> Self {
+        Self {
+            matcher,
+            frecency,
+            weight: Self::DEFAULT_WEIGHT,
+        }
+    }
+
+    /// Sets how strongly the [`Frecency`] score influences the ranking.
+    pub fn weight(mut self, weight: f32) -> Self {
+        self.weight = weight;
+        self
+    }
+}
+
+impl<'a, M: Matcher> Matcher for Boosted<'a, M> {
+    fn score(&self, query: &str, label: &str) -> Option<f32> {
+        let score = self.matcher.score(query, label)?;
+        let boost = self.weight * self.frecency.score(label).ln_1p();
+
+        // Empty queries score `0.0`, so the boost is added rather than
+        // multiplied to keep ranking by frecency alone.
+        Some(if score > 0.0 {
+            score * (1.0 + boost)
+        } else {
+            score + boost
+        })
+    }
+
+    fn error(&self, query: &str) -> Option<String> {
+        self.matcher.error(query)
+    }
+
+    fn highlights(&self, query: &str, label: &str) -> Vec<std::ops::Range<usize>> {
+        self.matcher.highlights(query, label)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::matcher::Substring;
+
+    const HOUR: Duration = Duration::from_secs(60 * 60);
+
+    fn entry(score: f32) -> Entry {
+        Entry {
+            score,
+            updated_at: 0,
+        }
+    }
+
+    #[test]
+    fn halves_after_every_half_life() {
+        let hour = HOUR.as_secs();
+
+        assert_eq!(entry(4.0).decayed(HOUR, 0), 4.0);
+        assert_eq!(entry(4.0).decayed(HOUR, hour), 2.0);
+        assert_eq!(entry(4.0).decayed(HOUR, 2 * hour), 1.0);
+        assert!((entry(4.0).decayed(HOUR, hour / 2) - 2.0_f32.powf(1.5)).abs() < 1e-5);
+    }
+
+    #[test]
+    fn ignores_updates_from_the_future() {
+        let entry = Entry {
+            score: 1.0,
+            updated_at: 10,
+        };
+
+        assert_eq!(entry.decayed(HOUR, 0), 1.0);
+    }
+
+    #[test]
+    fn adds_up_recorded_usages() {
+        let mut frecency = Frecency::new(HOUR);
+
+        frecency.record("a");
+        frecency.record("a");
+        frecency.record("b");
+
+        assert!((frecency.score("a") - 2.0).abs() < 0.01);
+        assert!((frecency.score("b") - 1.0).abs() < 0.01);
+        assert_eq!(frecency.score("c"), 0.0);
+
+        frecency.remove("a");
+
+        assert_eq!(frecency.score("a"), 0.0);
+    }
+
+    #[test]
+    fn adds_the_boost_to_empty_queries() {
+        let mut frecency = Frecency::new(HOUR);
+        frecency.record("used");
+
+        let boosted = Substring.boosted(&frecency);
+        let used = boosted.score("", "used").unwrap();
+
+        assert_eq!(boosted.score("", "unused"), Some(0.0));
+        assert!(used > 0.0);
+        assert!((used - Boosted::<Substring>::DEFAULT_WEIGHT * 2.0_f32.ln()).abs() < 0.01);
+    }
+
+    #[test]
+    fn multiplies_positive_scores_by_the_boost() {
+        let mut frecency = Frecency::new(HOUR);
+        frecency.record("use");
+
+        let boosted = Substring.boosted(&frecency).weight(1.0);
+        let score = Substring.score("us", "use").unwrap();
+
+        assert_eq!(boosted.score("us", "fuse"), Substring.score("us", "fuse"));
+        assert!((boosted.score("us", "use").unwrap() - score * (1.0 + 2.0_f32.ln())).abs() < 0.01);
+        assert_eq!(boosted.score("x", "use"), None);
+    }
+}
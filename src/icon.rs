@@ -0,0 +1,44 @@
+//! Configure the icons drawn inside a [`PickList`](crate::PickList).
+
+/// A glyph of a font, drawn as an icon.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Icon<Font> {
+    /// The font that contains the glyph.
+    pub font: Font,
+    /// The code point of the glyph.
+    pub code_point: char,
+    /// The size of the icon. Defaults to the text size of the
+    /// [`PickList`](crate::PickList).
+    pub size: Option<u16>,
+}
+
+/// The trailing icon of a [`PickList`](crate::PickList), which hints that it
+/// opens a dropdown.
+#[derive(Debug, Clone, PartialEq)]
+pub enum Handle<Font> {
+    /// The arrow icon of the built-in icon font of the renderer, sized
+    /// relative to the height of the [`PickList`](crate::PickList) unless a
+    /// size is given.
+    Arrow {
+        /// The size of the arrow.
+        size: Option<u16>,
+    },
+    /// The same [`Icon`] whether the dropdown is open or closed.
+    Static(Icon<Font>),
+    /// A different [`Icon`] depending on whether the dropdown is open, e.g.
+    /// an arrow pointing up while it is open.
+    Dynamic {
+        /// The [`Icon`] drawn while the dropdown is closed.
+        closed: Icon<Font>,
+        /// The [`Icon`] drawn while the dropdown is open.
+        open: Icon<Font>,
+    },
+    /// No trailing icon.
+    None,
+}
+
+impl<Font> Default for Handle<Font> {
+    fn default() -> Self {
+        Self::Arrow { size: None }
+    }
+}
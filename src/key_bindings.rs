@@ -0,0 +1,92 @@
+//! Bind keys to the actions of a [`PickList`](crate::PickList).
+use iced_native::keyboard::{KeyCode, Modifiers};
+
+/// An action of a [`PickList`](crate::PickList) that can be bound to a key.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum KeyAction {
+    /// Highlights the next option of the dropdown, or selects the next option
+    /// while the dropdown is closed.
+    Next,
+    /// Highlights the previous option of the dropdown, or selects the
+    /// previous option while the dropdown is closed.
+    Previous,
+    /// Highlights the option a page below the highlighted one.
+    PageDown,
+    /// Highlights the option a page above the highlighted one.
+    PageUp,
+    /// Selects the highlighted option.
+    Select,
+    /// Closes the dropdown without selecting an option, or unfocuses the
+    /// [`PickList`](crate::PickList) if it is already closed.
+    Close,
+    /// Completes the query with the label of the highlighted option.
+    Complete,
+    /// Clears the query or, if it is already empty, the selection.
+    Clear,
+}
+
+/// The keys bound to the [`KeyAction`]s of a [`PickList`](crate::PickList).
+///
+/// Besides the usual arrow, page, `Enter`, `Escape` and `Tab` keys, the
+/// default bindings include the emacs style `Ctrl+N` / `Ctrl+P` and the vim
+/// style `Ctrl+J` / `Ctrl+K` to move through the options, and `Ctrl+U` to
+/// clear the query.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct KeyBindings {
+    bindings: Vec<(KeyCode, Modifiers, KeyAction)>,
+}
+
+impl KeyBindings {
+    /// Creates [`KeyBindings`] without any bound keys.
+    pub fn new() -> Self {
+        Self {
+            bindings: Vec::new(),
+        }
+    }
+
+    /// Binds a key, pressed with exactly the given [`Modifiers`], to a
+    /// [`KeyAction`], replacing any previous binding of the key.
+    pub fn bind(mut self, key_code: KeyCode, modifiers: Modifiers, action: KeyAction) -> Self {
+        self = self.unbind(key_code, modifiers);
+        self.bindings.push((key_code, modifiers, action));
+        self
+    }
+
+    /// Removes the binding of a key pressed with the given [`Modifiers`].
+    pub fn unbind(mut self, key_code: KeyCode, modifiers: Modifiers) -> Self {
+        self.bindings
+            .retain(|binding| (binding.0, binding.1) != (key_code, modifiers));
+        self
+    }
+
+    /// Returns the [`KeyAction`] bound to a key pressed with the given
+    /// [`Modifiers`], if any.
+    pub fn action(&self, key_code: KeyCode, modifiers: Modifiers) -> Option<KeyAction> {
+        self.bindings
+            .iter()
+            .find(|binding| (binding.0, binding.1) == (key_code, modifiers))
+            .map(|binding| binding.2)
+    }
+}
+
+impl Default for KeyBindings {
+    fn default() -> Self {
+        let none = Modifiers::empty();
+        let ctrl = Modifiers::CTRL;
+
+        Self::new()
+            .bind(KeyCode::Down, none, KeyAction::Next)
+            .bind(KeyCode::Up, none, KeyAction::Previous)
+            .bind(KeyCode::N, ctrl, KeyAction::Next)
+            .bind(KeyCode::P, ctrl, KeyAction::Previous)
+            .bind(KeyCode::J, ctrl, KeyAction::Next)
+            .bind(KeyCode::K, ctrl, KeyAction::Previous)
+            .bind(KeyCode::PageDown, none, KeyAction::PageDown)
+            .bind(KeyCode::PageUp, none, KeyAction::PageUp)
+            .bind(KeyCode::Enter, none, KeyAction::Select)
+            .bind(KeyCode::NumpadEnter, none, KeyAction::Select)
+            .bind(KeyCode::Escape, none, KeyAction::Close)
+            .bind(KeyCode::Tab, none, KeyAction::Complete)
+            .bind(KeyCode::U, ctrl, KeyAction::Clear)
+    }
+}
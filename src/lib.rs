@@ -1,23 +1,53 @@
-#![allow(clippy::too_many_arguments)]
+#![allow(clippy::too_many_arguments, clippy::type_complexity)]
 
 //! Display a dropdown list of selectable values.
+pub mod matcher;
+pub mod operation;
+
+mod fields;
+mod frecency;
+mod icon;
+mod key_bindings;
+mod menu;
+mod normalize;
+#[cfg(feature = "regex")]
+mod pattern;
+mod query;
+mod recent;
+mod searchable;
+mod typo;
+
+use crate::menu::Menu;
+use crate::operation::Action;
 use iced_native::alignment;
 use iced_native::event::{self, Event};
 use iced_native::keyboard;
 use iced_native::layout;
 use iced_native::mouse;
 use iced_native::overlay;
-use iced_native::overlay::menu::{self, Menu};
 use iced_native::renderer;
 use iced_native::text::{self, Text};
+use iced_native::time::{Duration, Instant};
 use iced_native::touch;
-use iced_native::widget::text_input::{self, Id, Value};
-use iced_native::widget::{container, operation, scrollable, tree, Tree};
+use iced_native::widget::operation::{Focusable, Operation};
+use iced_native::widget::text_input::{self, Id, TextInput, Value};
+use iced_native::widget::{container, scrollable, tree, Tree};
 use iced_native::{
-    Clipboard, Element, Layout, Length, Padding, Point, Rectangle, Shell, Size, Widget,
+    Background, Clipboard, Color, Command, Element, Layout, Length, Padding, Point, Rectangle,
+    Shell, Size, Widget,
 };
 use std::borrow::Cow;
-
+use std::cell::RefCell;
+use std::ops::Range;
+
+pub use crate::fields::Fields;
+pub use crate::frecency::Frecency;
+pub use crate::icon::{Handle, Icon};
+pub use crate::key_bindings::{KeyAction, KeyBindings};
+pub use crate::matcher::Matcher;
+pub use crate::menu::{Empty, Placement, Row};
+pub use crate::recent::Recent;
+pub use crate::searchable::SearchablePickList;
 pub use iced_style::pick_list::StyleSheet;
 
 /// A widget for selecting a single value from a list of options.
@@ -39,16 +69,91 @@ where
     placeholder: Option<String>,
     selected: Option<T>,
     width: Length,
+    menu_width: Option<Length>,
+    menu_height: Length,
+    max_visible_items: Option<usize>,
+    placement: Placement,
+    menu_alignment: alignment::Horizontal,
     padding: Padding,
     text_size: Option<u16>,
     font: Renderer::Font,
+    handle: Handle<Renderer::Font>,
+    leading_icon: Option<Icon<Renderer::Font>>,
     style_sheet: <Renderer::Theme as StyleSheet>::Style,
     text_style_sheet: <Renderer::Theme as text_input::StyleSheet>::Style,
+    error_text_style_sheet: Option<<Renderer::Theme as text_input::StyleSheet>::Style>,
+    is_error: bool,
     value: text_input::Value,
     on_change: Box<dyn Fn(String) -> Message>,
     on_submit: Option<Message>,
     on_paste: Option<Box<dyn Fn(String) -> Message>>,
+    paste_transform: Option<Box<dyn Fn(&str) -> String>>,
+    select_on_paste: bool,
+    managed_query: bool,
+    searchable: bool,
+    wrap_around: bool,
+    close_on_select: bool,
+    clear_on_select: bool,
+    blur_policy: BlurPolicy,
+    on_commit: Option<Box<dyn Fn(String) -> Message>>,
+    key_bindings: KeyBindings,
     on_focus: Option<Message>,
+    on_empty: Option<Box<dyn Fn(bool) -> Message>>,
+    on_highlight: Option<Box<dyn Fn(Option<T>) -> Message>>,
+    on_cancel: Option<Message>,
+    on_open: Option<Message>,
+    on_close: Option<Box<dyn Fn(CloseReason) -> Message>>,
+    on_blur: Option<Message>,
+    on_clear: Option<Message>,
+    no_results: Option<Box<dyn Fn(&str) -> String>>,
+    no_results_element: Option<Element<'a, Message, Renderer>>,
+    recent: Option<&'a Recent<T>>,
+    recent_capacity: Option<usize>,
+    recent_label: String,
+    fields: Option<&'a Fields<'a, T>>,
+    highlight_matches: Option<Box<dyn Matcher + 'a>>,
+}
+
+/// The reason the dropdown of a [`PickList`] closed.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CloseReason {
+    /// An option was selected.
+    Selected,
+    /// The escape key was pressed.
+    Escape,
+    /// The mouse was pressed outside of the [`PickList`] and its dropdown.
+    ClickOutside,
+    /// The trailing handle of the [`PickList`] was pressed.
+    Handle,
+    /// An operation closed the dropdown or moved the focus away from the
+    /// [`PickList`], e.g. [`operation::close`].
+    ///
+    /// Operations cannot produce messages, so this is reported with the next
+    /// event the [`PickList`] processes.
+    Operation,
+    /// The [`PickList`] lost focus in any other way.
+    FocusLost,
+}
+
+/// What a [`PickList`] does with its query when it loses focus while its
+/// dropdown is open, e.g. when the mouse is pressed outside of it.
+///
+/// Closing the dropdown with the escape key or by selecting an option keeps
+/// the focus, and losing it afterwards never selects or commits.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum BlurPolicy {
+    /// The query is left as it is.
+    #[default]
+    Keep,
+    /// The query is reverted to the label of the selected option, producing
+    /// the `on_change` message.
+    Revert,
+    /// The only option that matches the query is selected, producing the
+    /// `on_selected` message.
+    SelectSingleMatch,
+    /// The query is committed as free text, producing the message set with
+    /// [`PickList::on_commit`].
+    Commit,
 }
 
 /// The local state of a [`PickList`].
@@ -58,7 +163,19 @@ pub struct State<T> {
     keyboard_modifiers: keyboard::Modifiers,
     is_open: bool,
     hovered_option: Option<usize>,
+    highlighted_option: Option<T>,
     last_selection: Option<T>,
+    last_completion: Option<String>,
+    pending_query: Option<String>,
+    committed_selection: Option<T>,
+    recent: Option<Recent<T>>,
+    is_empty: bool,
+    reported_open: bool,
+    reported_focus: bool,
+    rows: Option<Vec<menu::Row>>,
+    scrolled_pixels: f32,
+    type_ahead: String,
+    typed_at: Option<Instant>,
     text_input: text_input::State,
 }
 
@@ -70,7 +187,19 @@ impl<T> State<T> {
             keyboard_modifiers: keyboard::Modifiers::default(),
             is_open: bool::default(),
             hovered_option: Option::default(),
+            highlighted_option: Option::default(),
             last_selection: Option::default(),
+            last_completion: Option::default(),
+            pending_query: Option::default(),
+            committed_selection: Option::default(),
+            recent: Option::default(),
+            is_empty: bool::default(),
+            reported_open: bool::default(),
+            reported_focus: bool::default(),
+            rows: Option::default(),
+            scrolled_pixels: f32::default(),
+            type_ahead: String::default(),
+            typed_at: Option::default(),
             text_input: text_input::State::default(),
         }
     }
@@ -93,9 +222,129 @@ impl<T> State<T> {
 
         self.unfocus();
     }
+
+    /// Open the dropdown of the [`PickList`], highlighting the given option.
+    fn open(&mut self, hovered_option: Option<usize>) {
+        self.is_open = true;
+        self.hovered_option = hovered_option;
+        self.focus();
+        self.text_input.move_cursor_to_end();
+    }
+
+    /// Returns the index of the row of the dropdown showing the hovered
+    /// option.
+    fn hovered_row(&self) -> Option<usize> {
+        let hovered_option = self.hovered_option?;
+
+        match &self.rows {
+            Some(rows) => rows
+                .iter()
+                .position(|row| *row == menu::Row::Option(hovered_option)),
+            None => Some(hovered_option),
+        }
+    }
+
+    /// Moves the hovered option by `step` options in the order they are shown
+    /// in the dropdown, stopping at the ends unless `wrap_around` is set.
+    fn move_hovered_option(
+        &mut self,
+        options_len: usize,
+        forward: bool,
+        step: usize,
+        wrap_around: bool,
+    ) {
+        let order: Vec<usize> = match &self.rows {
+            Some(rows) => rows
+                .iter()
+                .filter_map(|row| match row {
+                    menu::Row::Option(index) => Some(*index),
+                    menu::Row::Header(_) | menu::Row::Completion { .. } => None,
+                })
+                .filter(|index| *index < options_len)
+                .collect(),
+            None => (0..options_len).collect(),
+        };
+
+        let Some(last) = order.len().checked_sub(1) else {
+            return;
+        };
+
+        let position = self
+            .hovered_option
+            .and_then(|hovered| order.iter().position(|index| *index == hovered));
+
+        let next = match position {
+            Some(position) if forward => match position.checked_add(step) {
+                Some(next) if next <= last => next,
+                _ if wrap_around => 0,
+                _ => last,
+            },
+            Some(position) => match position.checked_sub(step) {
+                Some(next) => next,
+                None if wrap_around => last,
+                None => 0,
+            },
+            None if forward => 0,
+            None => last,
+        };
+
+        self.hovered_option = Some(order[next]);
+    }
+
+    /// Appends a character to the type-ahead buffer and returns the index of
+    /// the first option whose label starts with it.
+    ///
+    /// Typing the same character repeatedly cycles through the options
+    /// starting with it, beginning after the `current` one.
+    fn type_ahead(&mut self, c: char, options: &[T], current: Option<usize>) -> Option<usize>
+    where
+        T: ToString,
+    {
+        let now = Instant::now();
+
+        if self
+            .typed_at
+            .is_none_or(|typed_at| now.duration_since(typed_at) > TYPE_AHEAD_TIMEOUT)
+        {
+            self.type_ahead.clear();
+        }
+
+        self.typed_at = Some(now);
+        self.type_ahead.extend(c.to_lowercase());
+
+        let labels: Vec<String> = options
+            .iter()
+            .map(|option| option.to_string().to_lowercase())
+            .collect();
+
+        let mut chars = self.type_ahead.chars();
+        let first = chars.next()?;
+
+        if chars.all(|c| c == first) {
+            let start = current.map_or(0, |current| current + 1);
+
+            (0..labels.len())
+                .map(|offset| (start + offset) % labels.len())
+                .find(|&index| labels[index].starts_with(first))
+        } else {
+            labels
+                .iter()
+                .position(|label| label.starts_with(&self.type_ahead))
+        }
+    }
 }
 
-impl<T> operation::Focusable for State<T> {
+/// The border color of a [`PickList`] with an invalid query.
+const ERROR_COLOR: Color = Color::from_rgb(0.8, 0.2, 0.2);
+
+/// The amount of options a page spans when the [`PickList`] does not limit
+/// its visible options.
+const PAGE_SIZE: usize = 10;
+
+/// The time after which the type-ahead buffer of a [`PickList`] is reset.
+const TYPE_AHEAD_TIMEOUT: Duration = Duration::from_secs(1);
+
+impl<T> Focusable for State<T> {
     fn is_focused(&self) -> bool {
         self.text_input.is_focused()
     }
@@ -147,16 +396,49 @@ where
             placeholder: None,
             selected,
             width: Length::Shrink,
+            menu_width: None,
+            menu_height: Length::Shrink,
+            max_visible_items: None,
+            placement: Placement::default(),
+            menu_alignment: alignment::Horizontal::Left,
             text_size: None,
             padding: Self::DEFAULT_PADDING,
             font: Default::default(),
+            handle: Handle::default(),
+            leading_icon: None,
             style_sheet: Default::default(),
             text_style_sheet: Default::default(),
+            error_text_style_sheet: None,
+            is_error: false,
             value: Value::new(value),
             on_change: Box::new(on_change),
             on_submit: None,
             on_paste: None,
+            paste_transform: None,
+            select_on_paste: false,
+            managed_query: false,
+            searchable: true,
+            wrap_around: false,
+            close_on_select: true,
+            clear_on_select: false,
+            blur_policy: BlurPolicy::default(),
+            on_commit: None,
+            key_bindings: KeyBindings::default(),
             on_focus: None,
+            on_empty: None,
+            on_highlight: None,
+            on_cancel: None,
+            on_open: None,
+            on_close: None,
+            on_blur: None,
+            on_clear: None,
+            no_results: None,
+            no_results_element: None,
+            recent: None,
+            recent_capacity: None,
+            recent_label: String::from("Recent"),
+            fields: None,
+            highlight_matches: None,
         }
     }
 
@@ -178,18 +460,272 @@ where
         self
     }
 
+    /// Sets the message that is produced when text is pasted into the
+    /// [`PickList`], instead of its `on_change` message.
+    pub fn on_paste(mut self, on_paste: impl Fn(String) -> Message + 'static) -> Self {
+        self.on_paste = Some(Box::new(on_paste));
+        self
+    }
+
+    /// Sets the function that transforms text pasted into the [`PickList`],
+    /// e.g. [`normalize_paste`].
+    pub fn paste_transform(mut self, transform: impl Fn(&str) -> String + 'static) -> Self {
+        self.paste_transform = Some(Box::new(transform));
+        self
+    }
+
+    /// Sets whether pasting the exact label of an option selects it directly.
+    pub fn select_on_paste(mut self, select_on_paste: bool) -> Self {
+        self.select_on_paste = select_on_paste;
+        self
+    }
+
+    /// Sets whether the [`PickList`] manages its query.
+    ///
+    /// A managed [`PickList`] produces `on_change` messages that reset the
+    /// query to the label of the selected option whenever the dropdown opens
+    /// or closes, and whenever the selection is changed by the application.
+    pub fn managed_query(mut self, managed_query: bool) -> Self {
+        self.managed_query = managed_query;
+        self
+    }
+
+    /// Sets whether the options of the [`PickList`] can be searched by
+    /// typing a query.
+    ///
+    /// A [`PickList`] that is not searchable ignores its query. Typing jumps
+    /// to the first option starting with the typed characters instead, and
+    /// `Enter` selects the highlighted option.
+    ///
+    /// Typing into a searchable [`PickList`] that is focused but closed opens
+    /// it and edits the query.
+    pub fn searchable(mut self, searchable: bool) -> Self {
+        self.searchable = searchable;
+        self
+    }
+
+    /// Sets whether cycling through the options with the arrow keys or the
+    /// mouse wheel wraps around at the first and last option.
+    ///
+    /// The arrow keys cycle the selection while the [`PickList`] is focused
+    /// and closed, e.g. after closing it with `Escape` or selecting an
+    /// option.
+    pub fn wrap_around(mut self, wrap_around: bool) -> Self {
+        self.wrap_around = wrap_around;
+        self
+    }
+
+    /// Sets whether the dropdown of the [`PickList`] closes when an option is
+    /// selected.
+    ///
+    /// Keeping the dropdown open allows picking many options in a row. Either
+    /// way, the [`PickList`] keeps the focus.
+    pub fn close_on_select(mut self, close_on_select: bool) -> Self {
+        self.close_on_select = close_on_select;
+        self
+    }
+
+    /// Sets whether the query is cleared when an option is selected while
+    /// the dropdown stays open. See [`PickList::close_on_select`].
+    pub fn clear_on_select(mut self, clear_on_select: bool) -> Self {
+        self.clear_on_select = clear_on_select;
+        self
+    }
+
+    /// Sets the [`BlurPolicy`] of the [`PickList`].
+    pub fn blur_policy(mut self, blur_policy: BlurPolicy) -> Self {
+        self.blur_policy = blur_policy;
+        self
+    }
+
+    /// Sets the message that is produced when the query is committed as free
+    /// text by [`BlurPolicy::Commit`].
+    pub fn on_commit(mut self, on_commit: impl Fn(String) -> Message + 'static) -> Self {
+        self.on_commit = Some(Box::new(on_commit));
+        self
+    }
+
+    /// Sets the [`KeyBindings`] of the [`PickList`].
+    pub fn key_bindings(mut self, key_bindings: KeyBindings) -> Self {
+        self.key_bindings = key_bindings;
+        self
+    }
+
     /// Sets the on_submit Message of the [`PickList`].
     pub fn on_focus(mut self, on_focus: Message) -> Self {
         self.on_focus = Some(on_focus);
         self
     }
 
+    /// Sets the message that is produced when the dropdown of the
+    /// [`PickList`] opens.
+    pub fn on_open(mut self, on_open: Message) -> Self {
+        self.on_open = Some(on_open);
+        self
+    }
+
+    /// Sets the message that is produced when the dropdown of the
+    /// [`PickList`] closes, given the [`CloseReason`].
+    pub fn on_close(mut self, on_close: impl Fn(CloseReason) -> Message + 'static) -> Self {
+        self.on_close = Some(Box::new(on_close));
+        self
+    }
+
+    /// Sets the message that is produced when the [`PickList`] loses focus.
+    pub fn on_blur(mut self, on_blur: Message) -> Self {
+        self.on_blur = Some(on_blur);
+        self
+    }
+
+    /// Sets the message that is produced when the selection of the
+    /// [`PickList`] is cleared.
+    ///
+    /// While an option is selected, a clear button is shown next to the arrow
+    /// icon. Pressing `Delete` or `Backspace` with an empty query clears the
+    /// selection as well.
+    pub fn on_clear(mut self, on_clear: Message) -> Self {
+        self.on_clear = Some(on_clear);
+        self
+    }
+
+    /// Sets the message that is produced when the options of an open
+    /// [`PickList`] become empty or non-empty, including when the dropdown
+    /// opens without options.
+    pub fn on_empty(mut self, on_empty: impl Fn(bool) -> Message + 'static) -> Self {
+        self.on_empty = Some(Box::new(on_empty));
+        self
+    }
+
+    /// Sets the message that is produced when the highlighted option in the
+    /// dropdown changes, by mouse or keyboard, or because the options changed
+    /// under it.
+    ///
+    /// This allows previewing an option before it is selected.
+    pub fn on_highlight(mut self, on_highlight: impl Fn(Option<T>) -> Message + 'static) -> Self {
+        self.on_highlight = Some(Box::new(on_highlight));
+        self
+    }
+
+    /// Sets the message that is produced when the dropdown closes without a
+    /// selection.
+    ///
+    /// This allows reverting a preview started with [`PickList::on_highlight`].
+    pub fn on_cancel(mut self, on_cancel: Message) -> Self {
+        self.on_cancel = Some(on_cancel);
+        self
+    }
+
+    /// Sets the text shown in the dropdown when there are no options, given
+    /// the current query, e.g. `No results for "…"`.
+    pub fn no_results(mut self, no_results: impl Fn(&str) -> String + 'static) -> Self {
+        self.no_results = Some(Box::new(no_results));
+        self
+    }
+
+    /// Sets the [`Element`] shown in the dropdown when there are no options.
+    ///
+    /// The element is only displayed and does not receive any events. It
+    /// takes precedence over the text set with [`PickList::no_results`].
+    pub fn no_results_element(
+        mut self,
+        element: impl Into<Element<'a, Message, Renderer>>,
+    ) -> Self {
+        self.no_results_element = Some(element.into());
+        self
+    }
+
     /// Sets the width of the [`PickList`].
     pub fn width(mut self, width: Length) -> Self {
         self.width = width;
         self
     }
 
+    /// Sets the [`Recent`] options of the [`PickList`], which are shown in a
+    /// separate section at the top of the dropdown while the query is empty,
+    /// and ranked first otherwise.
+    pub fn recent(mut self, recent: &'a Recent<T>) -> Self {
+        self.recent = Some(recent);
+        self
+    }
+
+    /// Makes the [`PickList`] record every option it selects in its own
+    /// [`Recent`] list, remembering up to `capacity` options.
+    ///
+    /// The [`Recent`] options set with [`PickList::recent`] are shown instead,
+    /// if any.
+    pub fn track_recent(mut self, capacity: usize) -> Self {
+        self.recent_capacity = Some(capacity);
+        self
+    }
+
+    /// Sets the header of the section of [`Recent`] options.
+    pub fn recent_label(mut self, label: impl Into<String>) -> Self {
+        self.recent_label = label.into();
+        self
+    }
+
+    /// Sets the [`Fields`] of the options of the [`PickList`], whose names are
+    /// completed in the dropdown while they are being typed.
+    ///
+    /// The options are still filtered by the application, e.g. with
+    /// [`Fields::filter`].
+    pub fn fields(mut self, fields: &'a Fields<'a, T>) -> Self {
+        self.fields = Some(fields);
+        self
+    }
+
+    /// Sets the [`Matcher`] whose [`Matcher::highlights`] are highlighted in
+    /// the options of the dropdown, usually the one filtering them.
+    ///
+    /// The query is shown as invalid while [`Matcher::error`] returns an
+    /// error for it.
+    pub fn highlight_matches(mut self, matcher: impl Matcher + 'a) -> Self {
+        self.highlight_matches = Some(Box::new(matcher));
+        self
+    }
+
+    /// Sets the width of the dropdown menu of the [`PickList`].
+    ///
+    /// By default the menu is as wide as the [`PickList`] itself.
+    /// [`Length::Shrink`] fits the widest option, but never gets narrower than
+    /// the [`PickList`].
+    pub fn menu_width(mut self, width: Length) -> Self {
+        self.menu_width = Some(width);
+        self
+    }
+
+    /// Sets the height of the dropdown menu of the [`PickList`].
+    ///
+    /// [`Length::Units`] caps the height of the menu, which scrolls when the
+    /// options do not fit.
+    pub fn menu_height(mut self, height: Length) -> Self {
+        self.menu_height = height;
+        self
+    }
+
+    /// Sets the maximum amount of options visible in the dropdown menu of the
+    /// [`PickList`] before it starts scrolling.
+    pub fn max_visible_items(mut self, max_visible_items: usize) -> Self {
+        self.max_visible_items = Some(max_visible_items);
+        self
+    }
+
+    /// Sets the [`Placement`] of the dropdown menu of the [`PickList`].
+    pub fn placement(mut self, placement: Placement) -> Self {
+        self.placement = placement;
+        self
+    }
+
+    /// Sets the horizontal alignment of the dropdown menu relative to the
+    /// [`PickList`].
+    ///
+    /// Aligning a wide menu to the right keeps it inside the window for
+    /// pick lists at the right edge of a panel.
+    pub fn menu_alignment(mut self, alignment: alignment::Horizontal) -> Self {
+        self.menu_alignment = alignment;
+        self
+    }
+
     /// Sets the [`Padding`] of the [`PickList`].
     pub fn padding<P: Into<Padding>>(mut self, padding: P) -> Self {
         self.padding = padding.into();
@@ -208,6 +744,19 @@ where
         self
     }
 
+    /// Sets the trailing [`Handle`] of the [`PickList`].
+    pub fn handle(mut self, handle: Handle<Renderer::Font>) -> Self {
+        self.handle = handle;
+        self
+    }
+
+    /// Sets the [`Icon`] drawn before the text of the [`PickList`], e.g. a
+    /// magnifying glass.
+    pub fn leading_icon(mut self, icon: Icon<Renderer::Font>) -> Self {
+        self.leading_icon = Some(icon);
+        self
+    }
+
     /// Sets the style of the [`PickList`].
     pub fn style(mut self, style: impl Into<<Renderer::Theme as StyleSheet>::Style>) -> Self {
         self.style_sheet = style.into();
@@ -222,6 +771,86 @@ where
         self.text_style_sheet = style.into();
         self
     }
+
+    /// Sets whether the query of the [`PickList`] is invalid.
+    ///
+    /// The query is also invalid whenever the [`Matcher::error`] of the
+    /// [`Matcher`] set with [`PickList::highlight_matches`] returns an error for it.
+    /// An invalid [`PickList`] is drawn with an error border and the style
+    /// set with [`PickList::error_text_style`].
+    pub fn error(mut self, is_error: bool) -> Self {
+        self.is_error = is_error;
+        self
+    }
+
+    /// Sets the style of the text input of the [`PickList`] while its query
+    /// is invalid.
+    pub fn error_text_style(
+        mut self,
+        style: impl Into<<Renderer::Theme as text_input::StyleSheet>::Style>,
+    ) -> Self {
+        self.error_text_style_sheet = Some(style.into());
+        self
+    }
+}
+
+/// Produces a [`Command`] that focuses the [`PickList`] with the given [`Id`].
+pub fn focus<Message: 'static>(id: Id) -> Command<Message> {
+    text_input::focus(id)
+}
+
+/// Produces a [`Command`] that moves the cursor of the [`PickList`] with the
+/// given [`Id`] to the front.
+pub fn move_cursor_to_front<Message: 'static>(id: Id) -> Command<Message> {
+    Command::widget(operation::move_cursor_to_front(id))
+}
+
+/// Produces a [`Command`] that moves the cursor of the [`PickList`] with the
+/// given [`Id`] to the end.
+pub fn move_cursor_to_end<Message: 'static>(id: Id) -> Command<Message> {
+    Command::widget(operation::move_cursor_to_end(id))
+}
+
+/// Produces a [`Command`] that moves the cursor of the [`PickList`] with the
+/// given [`Id`] to the given position.
+pub fn move_cursor_to<Message: 'static>(id: Id, position: usize) -> Command<Message> {
+    Command::widget(operation::move_cursor_to(id, position))
+}
+
+/// Produces a [`Command`] that selects all the text of the [`PickList`] with
+/// the given [`Id`].
+pub fn select_all<Message: 'static>(id: Id) -> Command<Message> {
+    Command::widget(operation::select_all(id))
+}
+
+/// Produces a [`Command`] that opens the dropdown of the [`PickList`] with the
+/// given [`Id`].
+pub fn open<Message: 'static>(id: Id) -> Command<Message> {
+    Command::widget(operation::open(id))
+}
+
+/// Produces a [`Command`] that closes the dropdown of the [`PickList`] with
+/// the given [`Id`].
+pub fn close<Message: 'static>(id: Id) -> Command<Message> {
+    Command::widget(operation::close(id))
+}
+
+/// Produces a [`Command`] that highlights the option at the given index in
+/// the dropdown of the [`PickList`] with the given [`Id`].
+pub fn preselect<Message: 'static>(id: Id, index: usize) -> Command<Message> {
+    Command::widget(operation::preselect(id, index))
+}
+
+/// Produces a [`Command`] that sets the query of the [`PickList`] with the
+/// given [`Id`], producing its `on_change` message.
+pub fn set_query<Message: 'static>(id: Id, query: impl Into<String>) -> Command<Message> {
+    Command::widget(operation::set_query(id, query.into()))
+}
+
+/// Trims the given text and collapses any whitespace, including line breaks,
+/// into single spaces.
+pub fn normalize_paste(text: &str) -> String {
+    text.split_whitespace().collect::<Vec<_>>().join(" ")
 }
 
 /// Computes the layout of a [`PickList`].
@@ -233,6 +862,7 @@ pub fn layout<Renderer, T>(
     text_size: Option<u16>,
     font: &Renderer::Font,
     placeholder: Option<&str>,
+    leading_icon: Option<&Icon<Renderer::Font>>,
     options: &[T],
 ) -> layout::Node
 where
@@ -251,6 +881,10 @@ where
 
     let text_size = text_size.unwrap_or_else(|| renderer.default_size());
 
+    let leading_width = leading_icon.map_or(0.0, |icon| {
+        f32::from(icon.size.unwrap_or(text_size)) + f32::from(padding.left)
+    });
+
     let max_width = match width {
         Length::Shrink => {
             let measure = |label: &str| -> u32 {
@@ -277,19 +911,167 @@ where
 
     let size = {
         let intrinsic = Size::new(
-            max_width as f32 + f32::from(text_size) + f32::from(padding.left),
+            max_width as f32 + f32::from(text_size) + f32::from(padding.left) + leading_width,
             f32::from(text_size),
         );
 
         limits.resolve(intrinsic).pad(padding)
     };
 
-    let mut text = layout::Node::new(limits.resolve(size));
-    text.move_to(Point::new(padding.left.into(), 0.));
+    let text_bounds = limits.resolve(size);
+
+    let mut text = layout::Node::new(Size::new(
+        (text_bounds.width - leading_width).max(0.0),
+        text_bounds.height,
+    ));
+    text.move_to(Point::new(f32::from(padding.left) + leading_width, 0.));
 
     layout::Node::with_children(size, vec![text])
 }
 
+/// Applies an [`Operation`] to the [`State`] of a [`PickList`].
+pub fn operate<T, Message, Renderer>(
+    state: &mut State<T>,
+    id: Option<&Id>,
+    layout: Layout<'_>,
+    operation: &mut dyn Operation<Message>,
+    selected: Option<&T>,
+    options: &[T],
+    on_query: &mut dyn FnMut(String),
+) where
+    T: PartialEq,
+    Message: Clone + 'static,
+    Renderer: text::Renderer,
+    Renderer::Theme: text_input::StyleSheet,
+{
+    operation.container(None, &mut |operation| {
+        match id {
+            Some(id) => {
+                match operation::pending(id) {
+                    Some(Action::MoveCursorToFront) => state.text_input.move_cursor_to_front(),
+                    Some(Action::MoveCursorToEnd) => state.text_input.move_cursor_to_end(),
+                    Some(Action::MoveCursorTo(position)) => {
+                        state.text_input.move_cursor_to(position)
+                    }
+                    Some(Action::SelectAll) => state.text_input.select_all(),
+                    Some(Action::Open) => {
+                        state.open(options.iter().position(|option| Some(option) == selected))
+                    }
+                    Some(Action::Close) => state.unfocus(),
+                    Some(Action::Preselect(index)) => {
+                        state.hovered_option = (index < options.len()).then_some(index)
+                    }
+                    Some(Action::SetQuery(query)) => {
+                        (on_query)(query);
+                        state.text_input.move_cursor_to_end();
+                    }
+                    None => {}
+                }
+
+                // `text_input::Id` does not expose the widget `Id` it wraps, so
+                // a `TextInput` reports it to the operation on our behalf.
+                let mut tree = Tree {
+                    tag: tree::Tag::of::<text_input::State>(),
+                    state: tree::State::new(std::mem::take(&mut state.text_input)),
+                    children: Vec::new(),
+                };
+
+                Widget::<Message, Renderer>::operate(
+                    &TextInput::new("", "", |_| unreachable!("the input is never updated"))
+                        .id(id.clone()),
+                    &mut tree,
+                    layout,
+                    operation,
+                );
+
+                state.text_input = std::mem::take(tree.state.downcast_mut());
+            }
+            None => operation.focusable(state, None),
+        }
+
+        if !state.text_input.is_focused() {
+            state.is_open = false;
+        }
+    });
+}
+
+/// The behavior and the optional messages of a [`PickList`] given to
+/// [`update`].
+///
+/// The [`Default`] matches a new [`PickList`] without any optional message.
+#[allow(missing_debug_implementations)]
+pub struct UpdateOptions<'a, T, Message> {
+    /// The message produced when text is pasted into the query.
+    pub on_paste: Option<&'a dyn Fn(String) -> Message>,
+    /// Transforms the pasted text.
+    pub paste_transform: Option<&'a dyn Fn(&str) -> String>,
+    /// Whether pasting the label of an option selects it.
+    pub select_on_paste: bool,
+    /// Whether the query shows the selected option while not searching.
+    pub managed_query: bool,
+    /// Whether the query can be edited.
+    pub searchable: bool,
+    /// Whether keyboard navigation wraps around at the ends of the options.
+    pub wrap_around: bool,
+    /// Whether selecting an option closes the dropdown.
+    pub close_on_select: bool,
+    /// Whether selecting an option clears the query, if the dropdown stays
+    /// open.
+    pub clear_on_select: bool,
+    /// What happens to the query when the [`PickList`] loses focus.
+    pub blur_policy: BlurPolicy,
+    /// The message produced when the query is committed as free text.
+    pub on_commit: Option<&'a dyn Fn(String) -> Message>,
+    /// The maximum number of options shown in the dropdown, which is also
+    /// the number of options moved by a page.
+    pub max_visible_items: Option<usize>,
+    /// The message produced when the query is submitted.
+    pub on_submit: Option<&'a Message>,
+    /// The message produced when the [`PickList`] is focused.
+    pub on_focus: Option<&'a Message>,
+    /// The message produced when the options become empty or non-empty.
+    pub on_empty: Option<&'a dyn Fn(bool) -> Message>,
+    /// The message produced when the highlighted option changes.
+    pub on_highlight: Option<&'a dyn Fn(Option<T>) -> Message>,
+    /// The message produced when the dropdown closes without a selection.
+    pub on_cancel: Option<&'a Message>,
+    /// The message produced when the dropdown opens.
+    pub on_open: Option<&'a Message>,
+    /// The message produced when the dropdown closes.
+    pub on_close: Option<&'a dyn Fn(CloseReason) -> Message>,
+    /// The message produced when the [`PickList`] loses focus.
+    pub on_blur: Option<&'a Message>,
+    /// The message produced when the selection is cleared.
+    pub on_clear: Option<&'a Message>,
+}
+
+impl<'a, T, Message> Default for UpdateOptions<'a, T, Message> {
+    fn default() -> Self {
+        Self {
+            on_paste: None,
+            paste_transform: None,
+            select_on_paste: false,
+            managed_query: false,
+            searchable: true,
+            wrap_around: false,
+            close_on_select: true,
+            clear_on_select: false,
+            blur_policy: BlurPolicy::default(),
+            on_commit: None,
+            max_visible_items: None,
+            on_submit: None,
+            on_focus: None,
+            on_empty: None,
+            on_highlight: None,
+            on_cancel: None,
+            on_open: None,
+            on_close: None,
+            on_blur: None,
+            on_clear: None,
+        }
+    }
+}
+
 /// Processes an [`Event`] and updates the [`State`] of a [`PickList`]
 /// accordingly.
 pub fn update<'a, T, Message, Renderer>(
@@ -305,14 +1087,14 @@ pub fn update<'a, T, Message, Renderer>(
     clipboard: &mut dyn Clipboard,
     value: &mut Value,
     size: Option<u16>,
+    padding: Padding,
     font: &Renderer::Font,
     on_change: &dyn Fn(String) -> Message,
-    on_paste: Option<&dyn Fn(String) -> Message>,
-    on_submit: &Option<Message>,
-    on_focus: &Option<Message>,
+    key_bindings: &KeyBindings,
+    update_options: UpdateOptions<'_, T, Message>,
 ) -> event::Status
 where
-    T: PartialEq + Clone + 'a,
+    T: PartialEq + Clone + ToString + 'a,
     Message: Clone,
     Renderer: text::Renderer,
     Renderer::Theme: StyleSheet
@@ -322,8 +1104,51 @@ where
         + text_input::StyleSheet,
     <Renderer::Theme as menu::StyleSheet>::Style: From<<Renderer::Theme as StyleSheet>::Style>,
 {
+    let UpdateOptions {
+        on_paste,
+        paste_transform,
+        select_on_paste,
+        managed_query,
+        searchable,
+        wrap_around,
+        close_on_select,
+        clear_on_select,
+        blur_policy,
+        on_commit,
+        max_visible_items,
+        on_submit,
+        on_focus,
+        on_empty,
+        on_highlight,
+        on_cancel,
+        on_open,
+        on_close,
+        on_blur,
+        on_clear,
+    } = update_options;
+
     let state = state();
+    // Operations open and close the dropdown outside of `update`, so the
+    // changes are detected against the last reported state instead.
+    let was_open = state.reported_open;
+    let was_focused = state.reported_focus;
+    let mut close_reason = (was_open && !state.is_open).then_some(CloseReason::Operation);
+    let mut picked = None;
+    let on_clear = on_clear.filter(|_| selected.is_some());
+    let is_query_empty = value.is_empty();
+    let hovered_option = state.hovered_option;
+    let mut is_typing = false;
+
+    // Set by an operation that could not produce the message itself.
+    if let Some(query) = state.pending_query.take() {
+        shell.publish((on_change)(query));
+    }
+
     let mut propagate_event = |state: &mut text_input::State| {
+        if !searchable {
+            return event::Status::Ignored;
+        }
+
         text_input::update(
             event.clone(),
             layout,
@@ -337,79 +1162,104 @@ where
             false,
             on_change,
             on_paste,
-            on_submit,
+            &on_submit.cloned(),
             || state,
         )
     };
 
-    match event.clone() {
+    let status = match event.clone() {
+        Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left))
+        | Event::Touch(touch::Event::FingerPressed { .. })
+            if on_clear.is_some()
+                && clear_bounds(layout.bounds(), padding, renderer, size)
+                    .contains(cursor_position) =>
+        {
+            if let Some(on_clear) = on_clear {
+                shell.publish(on_clear.clone());
+            }
+
+            event::Status::Captured
+        }
         Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left))
         | Event::Touch(touch::Event::FingerPressed { .. }) => {
             let event_status = if state.is_open {
-                // TODO: Encode cursor availability in the type system
-                if layout.bounds().contains(cursor_position) {
-                    if cursor_position.x >= 0.0 && cursor_position.y >= 0.0 {
-                        state.unfocus();
-                    } else {
-                        propagate_event(&mut state.text_input);
-                    }
+                // While the cursor is over the dropdown, the widget receives
+                // an unavailable, negative cursor position.
+                let is_over_overlay = cursor_position.x < 0.0 || cursor_position.y < 0.0;
+                let bounds = layout.bounds();
+
+                if is_over_overlay {
+                    // The dropdown handles its own clicks.
+                } else if handle_bounds(bounds, padding, renderer, size).contains(cursor_position) {
+                    state.unfocus();
+                    close_reason = Some(CloseReason::Handle);
+                } else if bounds.contains(cursor_position) {
+                    propagate_event(&mut state.text_input);
+                } else {
+                    state.unfocus();
+                    close_reason = Some(CloseReason::ClickOutside);
                 }
 
                 event::Status::Captured
             } else if layout.bounds().contains(cursor_position) {
-                state.is_open = true;
-                state.hovered_option = options.iter().position(|option| Some(option) == selected);
-                state.focus();
-                state.text_input.move_cursor_to_end();
+                let is_focused = state.text_input.is_focused();
+
+                state.open(options.iter().position(|option| Some(option) == selected));
                 propagate_event(&mut state.text_input);
-                if let Some(message) = on_focus.as_ref() {
+                if let Some(message) = on_focus.filter(|_| !is_focused) {
                     shell.publish(message.clone())
                 }
 
                 event::Status::Captured
             } else {
+                if state.text_input.is_focused() {
+                    state.unfocus();
+                }
+
                 event::Status::Ignored
             };
 
             if let Some(last_selection) = state.last_selection.take() {
-                shell.publish((on_selected)(last_selection));
+                shell.publish((on_selected)(last_selection.clone()));
+                picked = Some(last_selection);
 
-                state.is_open = false;
-                state.unfocus();
+                event::Status::Captured
+            } else if let Some(query) = state.last_completion.take() {
+                state.text_input.move_cursor_to(Value::new(&query).len());
+                shell.publish((on_change)(query));
 
                 event::Status::Captured
             } else {
                 event_status
             }
         }
-        Event::Mouse(mouse::Event::WheelScrolled {
-            delta: mouse::ScrollDelta::Lines { y, .. },
-        }) => {
+        Event::Mouse(mouse::Event::WheelScrolled { delta }) => {
             if state.keyboard_modifiers.command()
                 && layout.bounds().contains(cursor_position)
                 && !state.is_open
             {
-                fn find_next<'a, T: PartialEq>(
-                    selected: &'a T,
-                    mut options: impl Iterator<Item = &'a T>,
-                ) -> Option<&'a T> {
-                    let _ = options.find(|&option| option == selected);
-
-                    options.next()
-                }
+                // Touchpads scroll by pixels, so they are accumulated into
+                // lines of text before cycling.
+                let y = match delta {
+                    mouse::ScrollDelta::Lines { y, .. } => y,
+                    mouse::ScrollDelta::Pixels { y, .. } => {
+                        let line_height =
+                            f32::from(size.unwrap_or_else(|| renderer.default_size()));
+
+                        state.scrolled_pixels += y;
+
+                        if state.scrolled_pixels.abs() < line_height {
+                            0.0
+                        } else {
+                            std::mem::take(&mut state.scrolled_pixels)
+                        }
+                    }
+                };
 
                 let next_option = if y < 0.0 {
-                    if let Some(selected) = selected {
-                        find_next(selected, options.iter())
-                    } else {
-                        options.first()
-                    }
+                    cycle(selected, options, true, wrap_around)
                 } else if y > 0.0 {
-                    if let Some(selected) = selected {
-                        find_next(selected, options.iter().rev())
-                    } else {
-                        options.last()
-                    }
+                    cycle(selected, options, false, wrap_around)
                 } else {
                     None
                 };
@@ -423,6 +1273,185 @@ where
                 event::Status::Ignored
             }
         }
+        Event::Keyboard(keyboard::Event::KeyPressed {
+            key_code,
+            modifiers,
+        }) if state.text_input.is_focused()
+            && key_bindings.action(key_code, modifiers).is_some() =>
+        {
+            let hovered = state.hovered_option.and_then(|index| options.get(index));
+            let completion = state.rows.iter().flatten().find_map(|row| match row {
+                menu::Row::Completion { query, .. } => Some(query.clone()),
+                _ => None,
+            });
+
+            match key_bindings.action(key_code, modifiers) {
+                Some(action @ (KeyAction::Next | KeyAction::Previous)) => {
+                    let forward = action == KeyAction::Next;
+
+                    if state.is_open {
+                        state.move_hovered_option(options.len(), forward, 1, wrap_around);
+                    } else if let Some(next_option) = cycle(selected, options, forward, wrap_around)
+                    {
+                        shell.publish((on_selected)(next_option.clone()));
+                    }
+
+                    event::Status::Captured
+                }
+                Some(action @ (KeyAction::PageDown | KeyAction::PageUp)) if state.is_open => {
+                    let page = max_visible_items.unwrap_or(PAGE_SIZE);
+
+                    state.move_hovered_option(
+                        options.len(),
+                        action == KeyAction::PageDown,
+                        page,
+                        false,
+                    );
+
+                    event::Status::Captured
+                }
+                Some(KeyAction::Select) if state.is_open && hovered.is_some() => {
+                    if let Some(option) = hovered {
+                        shell.publish((on_selected)(option.clone()));
+                        picked = Some(option.clone());
+                    }
+
+                    event::Status::Captured
+                }
+                Some(KeyAction::Close) => {
+                    // The first press closes the dropdown and keeps the focus,
+                    // so the selection can still be cycled with the keyboard.
+                    if state.is_open {
+                        state.is_open = false;
+                    } else {
+                        state.unfocus();
+                    }
+
+                    close_reason = Some(CloseReason::Escape);
+
+                    event::Status::Captured
+                }
+                Some(KeyAction::Complete)
+                    if searchable
+                        && state.is_open
+                        && (completion.is_some() || hovered.is_some()) =>
+                {
+                    if let Some(query) = completion.or_else(|| hovered.map(ToString::to_string)) {
+                        state.text_input.move_cursor_to(Value::new(&query).len());
+                        shell.publish((on_change)(query));
+                    }
+
+                    event::Status::Captured
+                }
+                Some(KeyAction::Clear) => {
+                    if searchable && !is_query_empty {
+                        state.text_input.move_cursor_to_front();
+                        shell.publish((on_change)(String::new()));
+                    } else if let Some(on_clear) = on_clear {
+                        shell.publish(on_clear.clone());
+                    }
+
+                    event::Status::Captured
+                }
+                _ => propagate_event(&mut state.text_input),
+            }
+        }
+        Event::Keyboard(keyboard::Event::CharacterReceived(c))
+            if searchable
+                && state.text_input.is_focused()
+                && !state.is_open
+                && !c.is_control()
+                && !state.keyboard_modifiers.command() =>
+        {
+            // Typing into a focused but closed list starts a new search.
+            state.open(options.iter().position(|option| Some(option) == selected));
+            is_typing = true;
+
+            if managed_query {
+                state.text_input.select_all();
+            }
+
+            propagate_event(&mut state.text_input)
+        }
+        Event::Keyboard(keyboard::Event::CharacterReceived(c))
+            if !searchable
+                && state.text_input.is_focused()
+                && !c.is_control()
+                && !state.keyboard_modifiers.command() =>
+        {
+            let current = if state.is_open {
+                state.hovered_option
+            } else {
+                selected.and_then(|selected| options.iter().position(|option| option == selected))
+            };
+
+            if let Some(index) = state.type_ahead(c, options, current) {
+                if state.is_open {
+                    state.hovered_option = Some(index);
+                } else {
+                    shell.publish((on_selected)(options[index].clone()));
+                }
+            }
+
+            event::Status::Captured
+        }
+        Event::Keyboard(keyboard::Event::KeyPressed {
+            key_code: keyboard::KeyCode::Backspace | keyboard::KeyCode::Delete,
+            ..
+        }) if state.text_input.is_focused() && is_query_empty && on_clear.is_some() => {
+            if let Some(on_clear) = on_clear {
+                shell.publish(on_clear.clone());
+            }
+
+            event::Status::Captured
+        }
+        Event::Keyboard(keyboard::Event::KeyPressed {
+            key_code: keyboard::KeyCode::V,
+            modifiers,
+        }) if modifiers.command()
+            && searchable
+            && state.text_input.is_focused()
+            && (paste_transform.is_some() || select_on_paste) =>
+        {
+            let content = clipboard.read().unwrap_or_default();
+            let content: String = match paste_transform {
+                Some(transform) => (transform)(&content),
+                None => content,
+            }
+            .chars()
+            .filter(|c| !c.is_control())
+            .collect();
+
+            let pasted_option = options
+                .iter()
+                .find(|option| select_on_paste && option.to_string() == content);
+
+            if let Some(option) = pasted_option {
+                shell.publish((on_selected)(option.clone()));
+                picked = Some(option.clone());
+            } else {
+                let (start, end) = match state.text_input.cursor().state(value) {
+                    text_input::cursor::State::Index(index) => (index, index),
+                    text_input::cursor::State::Selection { start, end } => {
+                        (start.min(end), start.max(end))
+                    }
+                };
+
+                let content = Value::new(&content);
+                let length = content.len();
+
+                value.remove_many(start, end);
+                value.insert_many(start, content);
+                state.text_input.move_cursor_to(start + length);
+
+                shell.publish(match on_paste {
+                    Some(on_paste) => (on_paste)(value.to_string()),
+                    None => (on_change)(value.to_string()),
+                });
+            }
+
+            event::Status::Captured
+        }
         Event::Keyboard(keyboard::Event::ModifiersChanged(modifiers)) => {
             state.keyboard_modifiers = modifiers;
             propagate_event(&mut state.text_input);
@@ -430,17 +1459,230 @@ where
             event::Status::Ignored
         }
         _ => propagate_event(&mut state.text_input),
+    };
+
+    if state.is_open
+        && state.hovered_option != hovered_option
+        && matches!(event, Event::Keyboard(_))
+    {
+        if let Some(row) = state.hovered_row() {
+            let text_size = size.unwrap_or_else(|| renderer.default_size());
+
+            state
+                .menu
+                .scroll_to(row, f32::from(text_size + padding.vertical()));
+        }
+    }
+
+    if picked.is_some() {
+        if close_on_select {
+            state.is_open = false;
+            close_reason = Some(CloseReason::Selected);
+        } else if clear_on_select && !value.is_empty() {
+            state.text_input.move_cursor_to_front();
+            shell.publish((on_change)(String::new()));
+        }
+    }
+
+    if was_focused
+        && was_open
+        && !state.text_input.is_focused()
+        && picked.is_none()
+        && close_reason != Some(CloseReason::Escape)
+    {
+        match blur_policy {
+            BlurPolicy::Keep => {}
+            BlurPolicy::Revert => {
+                let label = selected.map(ToString::to_string).unwrap_or_default();
+
+                if !managed_query && label != value.to_string() {
+                    shell.publish((on_change)(label));
+                }
+            }
+            BlurPolicy::SelectSingleMatch => {
+                if let [option] = options {
+                    if !is_query_empty {
+                        shell.publish((on_selected)(option.clone()));
+                        close_reason = Some(CloseReason::Selected);
+                        picked = Some(option.clone());
+                    }
+                }
+            }
+            BlurPolicy::Commit => {
+                if let Some(on_commit) = on_commit.filter(|_| !is_query_empty) {
+                    shell.publish((on_commit)(value.to_string()));
+                }
+            }
+        }
+    }
+
+    if state.is_open && !state.text_input.is_focused() {
+        state.is_open = false;
+    }
+
+    if state.is_open {
+        if !was_open {
+            if let Some(on_open) = on_open {
+                shell.publish(on_open.clone());
+            }
+        }
+
+        if let Some(on_highlight) = on_highlight {
+            // The options may be filtered while the dropdown is open, so the
+            // highlighted option is compared rather than its index.
+            let option = state.hovered_option.and_then(|index| options.get(index));
+
+            if state.highlighted_option.as_ref() != option {
+                state.highlighted_option = option.cloned();
+
+                shell.publish((on_highlight)(option.cloned()));
+            }
+        }
+
+        if let Some(on_empty) = on_empty {
+            let is_empty = options.is_empty();
+
+            if state.is_empty != is_empty {
+                state.is_empty = is_empty;
+                shell.publish((on_empty)(is_empty));
+            }
+        }
+    } else if was_open {
+        let close_reason = close_reason.unwrap_or(CloseReason::FocusLost);
+
+        state.highlighted_option = None;
+        state.is_empty = false;
+
+        if let Some(on_close) = on_close {
+            shell.publish((on_close)(close_reason));
+        }
+
+        if close_reason != CloseReason::Selected {
+            if let Some(on_cancel) = on_cancel {
+                shell.publish(on_cancel.clone());
+            }
+        }
+    }
+
+    if was_focused && !state.text_input.is_focused() {
+        if let Some(on_blur) = on_blur {
+            shell.publish(on_blur.clone());
+        }
+    }
+
+    state.reported_open = state.is_open;
+    state.reported_focus = state.text_input.is_focused();
+
+    if managed_query {
+        let query = if state.is_open && !was_open && !is_typing {
+            state.text_input.select_all();
+
+            Some(selected)
+        } else if !state.is_open && (was_open || picked.is_some()) {
+            Some(picked.as_ref().or(selected))
+        } else if !state.text_input.is_focused() && state.committed_selection.as_ref() != selected {
+            Some(selected)
+        } else {
+            None
+        }
+        .map(|option| option.map(ToString::to_string).unwrap_or_default());
+
+        state.committed_selection = picked.or_else(|| selected.cloned());
+
+        if let Some(query) = query.filter(|query| *query != value.to_string()) {
+            shell.publish((on_change)(query));
+        }
+    }
+
+    status
+}
+
+/// Returns the option after the selected one, or before it if not
+/// `forward`, optionally wrapping around at the ends of the options.
+fn cycle<'a, T: PartialEq>(
+    selected: Option<&T>,
+    options: &'a [T],
+    forward: bool,
+    wrap_around: bool,
+) -> Option<&'a T> {
+    let first = if forward {
+        options.first()
+    } else {
+        options.last()
+    };
+
+    let Some(index) =
+        selected.and_then(|selected| options.iter().position(|option| option == selected))
+    else {
+        return first;
+    };
+
+    let next = if forward {
+        options.get(index + 1)
+    } else {
+        index.checked_sub(1).and_then(|index| options.get(index))
+    };
+
+    next.or(if wrap_around { first } else { None })
+}
+
+/// Returns the bounds of the trailing handle of a [`PickList`], which toggles
+/// its dropdown instead of editing the query.
+fn handle_bounds<Renderer: text::Renderer>(
+    bounds: Rectangle,
+    padding: Padding,
+    renderer: &Renderer,
+    text_size: Option<u16>,
+) -> Rectangle {
+    let text_size = f32::from(text_size.unwrap_or_else(|| renderer.default_size()));
+    let x = bounds.x + bounds.width - f32::from(padding.horizontal()) - text_size;
+
+    Rectangle {
+        x,
+        width: bounds.x + bounds.width - x,
+        ..bounds
+    }
+}
+
+/// Returns the bounds of the clear button of a [`PickList`], right before
+/// its arrow icon.
+fn clear_bounds<Renderer: text::Renderer>(
+    bounds: Rectangle,
+    padding: Padding,
+    renderer: &Renderer,
+    text_size: Option<u16>,
+) -> Rectangle {
+    let text_size = f32::from(text_size.unwrap_or_else(|| renderer.default_size()));
+
+    Rectangle {
+        x: bounds.x + bounds.width - f32::from(padding.horizontal()) - 2.0 * text_size,
+        width: text_size,
+        ..bounds
     }
 }
 
 /// Returns the current [`mouse::Interaction`] of a [`PickList`].
-pub fn mouse_interaction(layout: Layout<'_>, cursor_position: Point) -> mouse::Interaction {
+pub fn mouse_interaction<Renderer: text::Renderer>(
+    layout: Layout<'_>,
+    cursor_position: Point,
+    renderer: &Renderer,
+    padding: Padding,
+    text_size: Option<u16>,
+    is_clearable: bool,
+    searchable: bool,
+) -> mouse::Interaction {
     let text_bounds = layout.children().next().unwrap().bounds();
     let bounds = layout.bounds();
-    let is_mouse_over_text = text_bounds.contains(cursor_position);
+    let is_mouse_over_clear = is_clearable
+        && clear_bounds(bounds, padding, renderer, text_size).contains(cursor_position);
+    let is_mouse_over_text = searchable
+        && text_bounds.contains(cursor_position)
+        && !handle_bounds(bounds, padding, renderer, text_size).contains(cursor_position);
     let is_mouse_over = bounds.contains(cursor_position);
 
-    if is_mouse_over_text {
+    if is_mouse_over_clear {
+        mouse::Interaction::Pointer
+    } else if is_mouse_over_text {
         mouse::Interaction::Text
     } else if is_mouse_over {
         mouse::Interaction::Pointer
@@ -452,11 +1694,20 @@ pub fn mouse_interaction(layout: Layout<'_>, cursor_position: Point) -> mouse::I
 /// Returns the current overlay of a [`PickList`].
 pub fn overlay<'a, T, Message, Renderer>(
     layout: Layout<'_>,
+    renderer: &Renderer,
     state: &'a mut State<T>,
+    width: Option<Length>,
+    height: Length,
+    max_visible_items: Option<usize>,
+    placement: Placement,
+    alignment: alignment::Horizontal,
     padding: Padding,
     text_size: Option<u16>,
     font: Renderer::Font,
     options: &'a [T],
+    rows: Option<Vec<menu::Row>>,
+    highlights: Option<Box<dyn Fn(&str) -> Vec<Range<usize>> + 'a>>,
+    empty: Option<menu::Empty<'a, Message, Renderer>>,
     style_sheet: <Renderer::Theme as StyleSheet>::Style,
 ) -> Option<overlay::Element<'a, Message, Renderer>>
 where
@@ -473,13 +1724,47 @@ where
     if state.is_open {
         let bounds = layout.bounds();
 
+        let width = match width {
+            Some(Length::Shrink) => {
+                let text_size = text_size.unwrap_or_else(|| renderer.default_size());
+
+                let labels_width = options
+                    .iter()
+                    .map(|option| {
+                        let (width, _) = renderer.measure(
+                            &option.to_string(),
+                            text_size,
+                            font.clone(),
+                            Size::new(f32::INFINITY, f32::INFINITY),
+                        );
+
+                        width + f32::from(padding.horizontal())
+                    })
+                    .fold(bounds.width, f32::max);
+
+                Length::Units(labels_width.ceil() as u16)
+            }
+            Some(width) => width,
+            None => Length::Units(bounds.width.round() as u16),
+        };
+
+        state.rows = rows;
+
         let mut menu = Menu::new(
             &mut state.menu,
             options,
             &mut state.hovered_option,
             &mut state.last_selection,
+            &mut state.last_completion,
         )
-        .width(bounds.width.round() as u16)
+        .rows(state.rows.as_deref())
+        .highlights(highlights)
+        .empty(empty)
+        .width(width)
+        .height(height)
+        .max_visible_items(max_visible_items)
+        .placement(placement)
+        .alignment(alignment)
         .padding(padding)
         .font(font)
         .style(style_sheet);
@@ -488,7 +1773,7 @@ where
             menu = menu.text_size(text_size);
         }
 
-        Some(menu.overlay(layout.position(), bounds.height))
+        Some(menu.overlay(layout.position(), bounds.size()))
     } else {
         None
     }
@@ -506,6 +1791,11 @@ pub fn draw<T, Renderer>(
     font: &Renderer::Font,
     placeholder: Option<&str>,
     selected: Option<&T>,
+    is_clearable: bool,
+    handle: &Handle<Renderer::Font>,
+    leading_icon: Option<&Icon<Renderer::Font>>,
+    searchable: bool,
+    is_error: bool,
     style_sheet: &<Renderer::Theme as StyleSheet>::Style,
     text_style_sheet: &<Renderer::Theme as text_input::StyleSheet>::Style,
     theme: &Renderer::Theme,
@@ -539,23 +1829,14 @@ pub fn draw<T, Renderer>(
         style.background,
     );
 
-    renderer.fill_text(Text {
-        content: &Renderer::ARROW_DOWN_ICON.to_string(),
-        font: Renderer::ICON_FONT,
-        size: bounds.height * style.icon_size,
-        bounds: Rectangle {
-            x: bounds.x + bounds.width - f32::from(padding.horizontal()),
-            y: bounds.center_y(),
-            ..bounds
-        },
-        color: style.text_color,
-        horizontal_alignment: alignment::Horizontal::Right,
-        vertical_alignment: alignment::Vertical::Center,
-    });
+    let text_size = text_size.unwrap_or_else(|| renderer.default_size());
 
+    let text_bounds = layout.children().next().unwrap().bounds();
     let label = selected.map(ToString::to_string);
 
-    if state.text_input.is_focused() {
+    // A closed list keeps the focus after a selection, but shows the selected
+    // option until the query is edited again.
+    if searchable && state.is_open {
         text_input::draw(
             renderer,
             theme,
@@ -564,13 +1845,13 @@ pub fn draw<T, Renderer>(
             &state.text_input,
             value,
             placeholder.unwrap_or_default(),
-            text_size,
+            Some(text_size),
             font,
             false,
             text_style_sheet,
         );
     } else if let Some(label) = label.as_deref().or(placeholder) {
-        let text_size = f32::from(text_size.unwrap_or_else(|| renderer.default_size()));
+        let text_size = f32::from(text_size);
 
         renderer.fill_text(Text {
             content: label,
@@ -582,15 +1863,106 @@ pub fn draw<T, Renderer>(
                 style.placeholder_color
             },
             bounds: Rectangle {
-                x: bounds.x + f32::from(padding.left),
+                x: text_bounds.x,
                 y: bounds.center_y() - text_size / 2.0,
-                width: bounds.width - f32::from(padding.horizontal()),
+                width: text_bounds.width,
                 height: text_size,
             },
             horizontal_alignment: alignment::Horizontal::Left,
             vertical_alignment: alignment::Vertical::Top,
         });
     }
+
+    if is_error {
+        renderer.fill_quad(
+            renderer::Quad {
+                bounds,
+                border_color: ERROR_COLOR,
+                border_width: style.border_width.max(1.0),
+                border_radius: style.border_radius,
+            },
+            Background::Color(Color::TRANSPARENT),
+        );
+    }
+
+    // The text input fills its whole bounds, so the icons are drawn on top
+    // of it.
+    let handle = match handle {
+        Handle::Arrow { size } => Some((
+            Renderer::ICON_FONT,
+            Renderer::ARROW_DOWN_ICON,
+            size.map_or(bounds.height * style.icon_size, f32::from),
+        )),
+        Handle::Static(icon) => Some((
+            icon.font.clone(),
+            icon.code_point,
+            f32::from(icon.size.unwrap_or(text_size)),
+        )),
+        Handle::Dynamic { closed, open } => {
+            let icon = if state.is_open { open } else { closed };
+
+            Some((
+                icon.font.clone(),
+                icon.code_point,
+                f32::from(icon.size.unwrap_or(text_size)),
+            ))
+        }
+        Handle::None => None,
+    };
+
+    if let Some((font, code_point, size)) = handle {
+        renderer.fill_text(Text {
+            content: &code_point.to_string(),
+            font,
+            size,
+            bounds: Rectangle {
+                x: bounds.x + bounds.width - f32::from(padding.horizontal()),
+                y: bounds.center_y(),
+                ..bounds
+            },
+            color: style.text_color,
+            horizontal_alignment: alignment::Horizontal::Right,
+            vertical_alignment: alignment::Vertical::Center,
+        });
+    }
+
+    if let Some(icon) = leading_icon {
+        renderer.fill_text(Text {
+            content: &icon.code_point.to_string(),
+            font: icon.font.clone(),
+            size: f32::from(icon.size.unwrap_or(text_size)),
+            bounds: Rectangle {
+                x: bounds.x + f32::from(padding.left),
+                y: bounds.center_y(),
+                ..bounds
+            },
+            color: style.placeholder_color,
+            horizontal_alignment: alignment::Horizontal::Left,
+            vertical_alignment: alignment::Vertical::Center,
+        });
+    }
+
+    if is_clearable && is_selected {
+        let clear_bounds = clear_bounds(bounds, padding, renderer, Some(text_size));
+
+        renderer.fill_text(Text {
+            content: "\u{00d7}",
+            size: clear_bounds.width,
+            font: font.clone(),
+            color: if clear_bounds.contains(cursor_position) {
+                style.text_color
+            } else {
+                style.placeholder_color
+            },
+            bounds: Rectangle {
+                x: clear_bounds.center_x(),
+                y: clear_bounds.center_y(),
+                ..clear_bounds
+            },
+            horizontal_alignment: alignment::Horizontal::Center,
+            vertical_alignment: alignment::Vertical::Center,
+        });
+    }
 }
 
 impl<'a, T: 'static, Message, Renderer> Widget<Message, Renderer>
@@ -632,10 +2004,36 @@ where
             self.text_size,
             &self.font,
             self.placeholder.as_deref(),
+            self.leading_icon.as_ref(),
             &self.options,
         )
     }
 
+    fn operate(&self, tree: &mut Tree, layout: Layout<'_>, operation: &mut dyn Operation<Message>) {
+        let state = tree.state.downcast_mut::<State<T>>();
+        let mut pending_query = None;
+
+        operate::<T, Message, Renderer>(
+            state,
+            self.id.as_ref(),
+            layout,
+            operation,
+            self.selected.as_ref(),
+            &self.options,
+            &mut |query| {
+                if operation::produces::<Message>() {
+                    operation::output((self.on_change)(query));
+                } else {
+                    pending_query = Some(query);
+                }
+            },
+        );
+
+        if pending_query.is_some() {
+            state.pending_query = pending_query;
+        }
+    }
+
     fn on_event(
         &mut self,
         tree: &mut Tree,
@@ -646,12 +2044,21 @@ where
         clipboard: &mut dyn Clipboard,
         shell: &mut Shell<'_, Message>,
     ) -> event::Status {
-        update(
+        let picks = RefCell::new(Vec::new());
+        let on_selected = |option: T| {
+            if self.recent_capacity.is_some() {
+                picks.borrow_mut().push(option.clone());
+            }
+
+            (self.on_selected)(option)
+        };
+
+        let status = update(
             event,
             layout,
             cursor_position,
             shell,
-            self.on_selected.as_ref(),
+            &on_selected,
             self.selected.as_ref(),
             &self.options,
             || tree.state.downcast_mut::<State<T>>(),
@@ -659,12 +2066,44 @@ where
             clipboard,
             &mut self.value,
             self.text_size,
+            self.padding,
             &self.font,
             &self.on_change,
-            self.on_paste.as_deref(),
-            &self.on_submit,
-            &self.on_focus,
-        )
+            &self.key_bindings,
+            UpdateOptions {
+                on_paste: self.on_paste.as_deref(),
+                paste_transform: self.paste_transform.as_deref(),
+                select_on_paste: self.select_on_paste,
+                managed_query: self.managed_query,
+                searchable: self.searchable,
+                wrap_around: self.wrap_around,
+                close_on_select: self.close_on_select,
+                clear_on_select: self.clear_on_select,
+                blur_policy: self.blur_policy,
+                on_commit: self.on_commit.as_deref(),
+                max_visible_items: self.max_visible_items,
+                on_submit: self.on_submit.as_ref(),
+                on_focus: self.on_focus.as_ref(),
+                on_empty: self.on_empty.as_deref(),
+                on_highlight: self.on_highlight.as_deref(),
+                on_cancel: self.on_cancel.as_ref(),
+                on_open: self.on_open.as_ref(),
+                on_close: self.on_close.as_deref(),
+                on_blur: self.on_blur.as_ref(),
+                on_clear: self.on_clear.as_ref(),
+            },
+        );
+
+        if let Some(capacity) = self.recent_capacity {
+            let state = tree.state.downcast_mut::<State<T>>();
+            let recent = state.recent.get_or_insert_with(|| Recent::new(capacity));
+
+            for option in picks.into_inner() {
+                recent.record(option);
+            }
+        }
+
+        status
     }
 
     fn mouse_interaction(
@@ -673,9 +2112,17 @@ where
         layout: Layout<'_>,
         cursor_position: Point,
         _viewport: &Rectangle,
-        _renderer: &Renderer,
+        renderer: &Renderer,
     ) -> mouse::Interaction {
-        mouse_interaction(layout, cursor_position)
+        mouse_interaction(
+            layout,
+            cursor_position,
+            renderer,
+            self.padding,
+            self.text_size,
+            self.on_clear.is_some() && self.selected.is_some(),
+            self.searchable,
+        )
     }
 
     fn draw(
@@ -688,6 +2135,12 @@ where
         cursor_position: Point,
         _viewport: &Rectangle,
     ) {
+        let is_error = self.is_error
+            || self
+                .highlight_matches
+                .as_ref()
+                .is_some_and(|matcher| matcher.error(&self.value.to_string()).is_some());
+
         draw(
             renderer,
             layout,
@@ -699,8 +2152,16 @@ where
             &self.font,
             self.placeholder.as_deref(),
             self.selected.as_ref(),
+            self.on_clear.is_some(),
+            &self.handle,
+            self.leading_icon.as_ref(),
+            self.searchable,
+            is_error,
             &self.style_sheet,
-            &self.text_style_sheet,
+            match &self.error_text_style_sheet {
+                Some(style) if is_error => style,
+                _ => &self.text_style_sheet,
+            },
             theme,
         )
     }
@@ -709,16 +2170,61 @@ where
         &'b self,
         tree: &'b mut Tree,
         layout: Layout<'_>,
-        _renderer: &Renderer,
-    ) -> Option<overlay::Element<'_, Message, Renderer>> {
+        renderer: &Renderer,
+    ) -> Option<overlay::Element<'b, Message, Renderer>> {
         let state = tree.state.downcast_mut::<State<T>>();
+        let empty = if let Some(element) = &self.no_results_element {
+            Some(menu::Empty::Element(element))
+        } else {
+            self.no_results
+                .as_ref()
+                .map(|no_results| menu::Empty::Text(no_results(&self.value.to_string())))
+        };
+
+        let query = self.value.to_string();
+        let highlights = self.highlight_matches.as_ref().map(|matcher| {
+            Box::new(move |label: &str| matcher.highlights(&query, label))
+                as Box<dyn Fn(&str) -> Vec<Range<usize>>>
+        });
+
+        let recent = self.recent.or(state.recent.as_ref());
+
+        let rows = recent.and_then(|recent| {
+            let header = self.value.is_empty().then_some(self.recent_label.as_str());
+
+            recent.rows(&self.options, header)
+        });
+
+        let completions = self
+            .fields
+            .map(|fields| fields.rows(&self.value.to_string()))
+            .unwrap_or_default();
+
+        let rows = if completions.is_empty() {
+            rows
+        } else {
+            let rows =
+                rows.unwrap_or_else(|| (0..self.options.len()).map(menu::Row::Option).collect());
+
+            Some(completions.into_iter().chain(rows).collect())
+        };
+
         overlay(
             layout,
+            renderer,
             state,
+            self.menu_width,
+            self.menu_height,
+            self.max_visible_items,
+            self.placement,
+            self.menu_alignment,
             self.padding,
             self.text_size,
             self.font.clone(),
             &self.options,
+            rows,
+            highlights,
+            empty,
             self.style_sheet.clone(),
         )
     }
@@ -742,3 +2248,106 @@ where
         Element::new(val)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const OPTIONS: [&str; 3] = ["a", "b", "c"];
+
+    fn hovered(rows: Option<Vec<menu::Row>>, hovered: Option<usize>) -> State<&'static str> {
+        let mut state = State::new();
+        state.rows = rows;
+        state.hovered_option = hovered;
+        state
+    }
+
+    fn moved(
+        mut state: State<&'static str>,
+        forward: bool,
+        step: usize,
+        wrap_around: bool,
+    ) -> Option<usize> {
+        state.move_hovered_option(OPTIONS.len(), forward, step, wrap_around);
+        state.hovered_option
+    }
+
+    #[test]
+    fn cycles_selection() {
+        assert_eq!(cycle(Some(&"a"), &OPTIONS, true, false), Some(&"b"));
+        assert_eq!(cycle(Some(&"b"), &OPTIONS, false, false), Some(&"a"));
+    }
+
+    #[test]
+    fn cycles_from_no_selection() {
+        assert_eq!(cycle(None, &OPTIONS, true, false), Some(&"a"));
+        assert_eq!(cycle(None, &OPTIONS, false, false), Some(&"c"));
+        assert_eq!(cycle(Some(&"z"), &OPTIONS, true, false), Some(&"a"));
+        assert_eq!(cycle::<&str>(None, &[], true, true), None);
+    }
+
+    #[test]
+    fn stops_cycling_at_the_ends() {
+        assert_eq!(cycle(Some(&"c"), &OPTIONS, true, false), None);
+        assert_eq!(cycle(Some(&"a"), &OPTIONS, false, false), None);
+    }
+
+    #[test]
+    fn cycles_around_the_ends() {
+        assert_eq!(cycle(Some(&"c"), &OPTIONS, true, true), Some(&"a"));
+        assert_eq!(cycle(Some(&"a"), &OPTIONS, false, true), Some(&"c"));
+    }
+
+    #[test]
+    fn moves_hovered_option() {
+        assert_eq!(moved(hovered(None, Some(0)), true, 1, false), Some(1));
+        assert_eq!(moved(hovered(None, Some(1)), false, 1, false), Some(0));
+        assert_eq!(moved(hovered(None, None), true, 1, false), Some(0));
+        assert_eq!(moved(hovered(None, None), false, 1, false), Some(2));
+    }
+
+    #[test]
+    fn stops_hovered_option_at_the_ends() {
+        assert_eq!(moved(hovered(None, Some(2)), true, 1, false), Some(2));
+        assert_eq!(moved(hovered(None, Some(0)), false, 1, false), Some(0));
+        // Pages stop at the ends instead of wrapping around.
+        assert_eq!(moved(hovered(None, Some(1)), true, 10, false), Some(2));
+        assert_eq!(moved(hovered(None, Some(1)), false, 10, false), Some(0));
+    }
+
+    #[test]
+    fn wraps_hovered_option_around() {
+        assert_eq!(moved(hovered(None, Some(2)), true, 1, true), Some(0));
+        assert_eq!(moved(hovered(None, Some(0)), false, 1, true), Some(2));
+    }
+
+    #[test]
+    fn skips_headers_and_completions() {
+        let rows = || {
+            Some(vec![
+                menu::Row::Completion {
+                    label: String::from("lang:"),
+                    query: String::from("lang:"),
+                },
+                menu::Row::Header(String::from("Recent")),
+                menu::Row::Option(2),
+                menu::Row::Header(String::from("All")),
+                menu::Row::Option(0),
+                menu::Row::Option(1),
+            ])
+        };
+
+        assert_eq!(moved(hovered(rows(), None), true, 1, false), Some(2));
+        assert_eq!(moved(hovered(rows(), Some(2)), true, 1, false), Some(0));
+        assert_eq!(moved(hovered(rows(), Some(0)), false, 1, false), Some(2));
+        assert_eq!(moved(hovered(rows(), Some(2)), false, 1, true), Some(1));
+        assert_eq!(moved(hovered(rows(), Some(1)), true, 1, true), Some(2));
+    }
+
+    #[test]
+    fn ignores_rows_of_removed_options() {
+        let rows = Some(vec![menu::Row::Option(0), menu::Row::Option(5)]);
+
+        assert_eq!(moved(hovered(rows, Some(0)), true, 1, false), Some(0));
+    }
+}
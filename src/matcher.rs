@@ -0,0 +1,305 @@
+//! Match and rank options against the query of a [`PickList`](crate::PickList).
+use crate::frecency::Frecency;
+use crate::normalize::{self, Folded};
+
+use std::ops::Range;
+
+pub use crate::frecency::Boosted;
+pub use crate::normalize::{normalize, Normalized};
+#[cfg(feature = "regex")]
+pub use crate::pattern::Regex;
+pub use crate::query::{Extended, Query, Term, TermKind};
+pub use crate::typo::Typo;
+
+/// A strategy to match the label of an option against a query.
+pub trait Matcher {
+    /// Returns the score of the `label` for the `query`, or `None` if it does
+    /// not match.
+    ///
+    /// Higher scores are ranked first. An empty query should match every
+    /// label with the same score.
+    fn score(&self, query: &str, label: &str) -> Option<f32>;
+
+    /// Returns why the `query` is invalid, e.g. a malformed pattern, or `None`
+    /// if it is valid.
+    fn error(&self, _query: &str) -> Option<String> {
+        None
+    }
+
+    /// Returns the byte ranges of the `label` that match the `query`, which
+    /// are highlighted in the dropdown.
+    fn highlights(&self, _query: &str, _label: &str) -> Vec<Range<usize>> {
+        Vec::new()
+    }
+
+    /// Normalizes the query and the labels before matching them, so
+    /// diacritics, compatibility forms and case are ignored.
+    fn normalized(self) -> Normalized<Self>
+    where
+        Self: Sized,
+    {
+        Normalized::new(self)
+    }
+
+    /// Also matches labels with a few typos in the query, ranked below the
+    /// labels that match without them.
+    fn typo_tolerant(self) -> Typo<Self>
+    where
+        Self: Sized,
+    {
+        Typo::new(self)
+    }
+
+    /// Boosts the score of the labels that are used frequently and recently,
+    /// according to the given [`Frecency`].
+    fn boosted(self, frecency: &Frecency) -> Boosted<'_, Self>
+    where
+        Self: Sized,
+    {
+        Boosted::new(self, frecency)
+    }
+}
+
+impl<M: Matcher + ?Sized> Matcher for &M {
+    fn score(&self, query: &str, label: &str) -> Option<f32> {
+        (**self).score(query, label)
+    }
+
+    fn error(&self, query: &str) -> Option<String> {
+        (**self).error(query)
+    }
+
+    fn highlights(&self, query: &str, label: &str) -> Vec<Range<usize>> {
+        (**self).highlights(query, label)
+    }
+}
+
+/// Matches labels that contain the query, ignoring case.
+///
+/// Matches closer to the start of shorter labels are ranked first.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct Substring;
+
+impl Matcher for Substring {
+    fn score(&self, query: &str, label: &str) -> Option<f32> {
+        if query.is_empty() {
+            return Some(0.0);
+        }
+
+        let query = query.to_lowercase();
+        let label = label.to_lowercase();
+
+        let start = label.find(&query)?;
+        let start = label[..start].chars().count();
+
+        let coverage = query.chars().count() as f32 / label.chars().count() as f32;
+
+        Some(1.0 / (1.0 + start as f32) + coverage)
+    }
+
+    fn highlights(&self, query: &str, label: &str) -> Vec<Range<usize>> {
+        if query.is_empty() {
+            return Vec::new();
+        }
+
+        let query = query.to_lowercase();
+        let label = Folded::lowercase(label);
+
+        match label.as_str().find(&query) {
+            Some(start) => label.origins(Some(start..start + query.len())),
+            None => Vec::new(),
+        }
+    }
+}
+
+/// Matches labels that contain every character of the query in order,
+/// ignoring case.
+///
+/// Consecutive characters and matches at the start of words are ranked
+/// first.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct Fuzzy;
+
+impl Matcher for Fuzzy {
+    fn score(&self, query: &str, label: &str) -> Option<f32> {
+        if query.is_empty() {
+            return Some(0.0);
+        }
+
+        let mut query = query.chars().flat_map(char::to_lowercase).peekable();
+        let mut score = 0.0;
+        let mut previous: Option<char> = None;
+        let mut is_consecutive = false;
+
+        for c in label.chars() {
+            let Some(&next) = query.peek() else {
+                break;
+            };
+
+            let is_match = c.to_lowercase().eq(std::iter::once(next));
+
+            if is_match {
+                let is_word_start = previous.is_none_or(|previous| {
+                    !previous.is_alphanumeric() || (previous.is_lowercase() && c.is_uppercase())
+                });
+
+                score += 1.0;
+
+                if is_consecutive {
+                    score += 1.0;
+                }
+
+                if is_word_start {
+                    score += 2.0;
+                }
+
+                let _ = query.next();
+            }
+
+            is_consecutive = is_match;
+            previous = Some(c);
+        }
+
+        if query.peek().is_some() {
+            return None;
+        }
+
+        Some(score / label.chars().count() as f32)
+    }
+
+    fn highlights(&self, query: &str, label: &str) -> Vec<Range<usize>> {
+        let mut query = query.chars().flat_map(char::to_lowercase).peekable();
+        let mut highlights = Vec::new();
+
+        for (index, c) in label.char_indices() {
+            let Some(&next) = query.peek() else {
+                break;
+            };
+
+            if c.to_lowercase().eq(std::iter::once(next)) {
+                highlights.push(index..index + c.len_utf8());
+
+                let _ = query.next();
+            }
+        }
+
+        if query.peek().is_some() {
+            return Vec::new();
+        }
+
+        normalize::merge(highlights)
+    }
+}
+
+/// Filters the options that match the query and sorts them by descending
+/// score.
+///
+/// Options with the same score keep their relative order.
+pub fn filter<T>(matcher: &impl Matcher, query: &str, options: &[T]) -> Vec<T>
+where
+    T: Clone + ToString,
+{
+    rank(options, |option| matcher.score(query, &option.to_string()))
+}
+
+/// Filters the options with a score and sorts them by descending score,
+/// keeping the relative order of options with the same score.
+pub(crate) fn rank<T: Clone>(options: &[T], score: impl Fn(&T) -> Option<f32>) -> Vec<T> {
+    let mut matches: Vec<(f32, &T)> = options
+        .iter()
+        .filter_map(|option| score(option).map(|score| (score, option)))
+        .collect();
+
+    matches.sort_by(|(a, _), (b, _)| b.total_cmp(a));
+
+    matches
+        .into_iter()
+        .map(|(_, option)| option.clone())
+        .collect()
+}
+
+#[cfg(test)]
+#[allow(clippy::single_range_in_vec_init)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn matches_substrings_ignoring_case() {
+        assert!(Substring.score("CAR", "Sidecar").is_some());
+        assert_eq!(Substring.score("rac", "Sidecar"), None);
+        assert_eq!(Substring.score("", "Sidecar"), Some(0.0));
+    }
+
+    #[test]
+    fn ranks_earlier_substrings_first() {
+        let start = Substring.score("car", "Cargo").unwrap();
+        let end = Substring.score("car", "Racecar").unwrap();
+
+        assert!(start > end);
+    }
+
+    #[test]
+    fn ranks_shorter_labels_first() {
+        let short = Substring.score("car", "Cart").unwrap();
+        let long = Substring.score("car", "Cartography").unwrap();
+
+        assert!(short > long);
+        assert_eq!(Substring.score("car", "car"), Some(2.0));
+    }
+
+    #[test]
+    fn highlights_substrings() {
+        assert_eq!(Substring.highlights("CAR", "Sidecar"), [4..7]);
+        assert!(Substring.highlights("", "Sidecar").is_empty());
+        assert!(Substring.highlights("rac", "Sidecar").is_empty());
+    }
+
+    #[test]
+    fn matches_characters_in_order() {
+        assert!(Fuzzy.score("sbtrkt", "Subterranean Kettle").is_some());
+        assert_eq!(Fuzzy.score("tks", "Subterranean Kettle"), None);
+        assert_eq!(Fuzzy.score("", "Kettle"), Some(0.0));
+    }
+
+    #[test]
+    fn ranks_word_starts_first() {
+        // Same length, so only the position of the matches differs.
+        let word_start = Fuzzy.score("fb", "foo bar").unwrap();
+        let inner = Fuzzy.score("ob", "foo bar").unwrap();
+
+        assert!(word_start > inner);
+        assert!(Fuzzy.score("fb", "fooBar").unwrap() > Fuzzy.score("fb", "foobar").unwrap());
+    }
+
+    #[test]
+    fn ranks_consecutive_characters_first() {
+        let consecutive = Fuzzy.score("ab", "xabxxx").unwrap();
+        let scattered = Fuzzy.score("ab", "xaxxbx").unwrap();
+
+        assert!(consecutive > scattered);
+    }
+
+    #[test]
+    fn highlights_fuzzy_matches() {
+        assert_eq!(Fuzzy.highlights("fba", "Foo bar"), [0..1, 4..6]);
+        assert!(Fuzzy.highlights("fbz", "Foo bar").is_empty());
+    }
+
+    #[test]
+    fn filters_by_descending_score() {
+        let options = ["Racecar", "Cargo", "Bike"];
+
+        assert_eq!(filter(&Substring, "car", &options), ["Cargo", "Racecar"]);
+    }
+
+    #[test]
+    fn keeps_the_order_of_equal_scores() {
+        let options = ["b", "c", "a", "d"];
+
+        assert_eq!(filter(&Substring, "", &options), options);
+        assert_eq!(
+            filter(&Substring, "x", &["ax", "bx", "cx"]),
+            ["ax", "bx", "cx"]
+        );
+    }
+}
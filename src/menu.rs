@@ -0,0 +1,1108 @@
+//! Build and show the dropdown menu of a [`PickList`](crate::PickList).
+//!
+//! This is a fork of [`iced_native::overlay::menu`] that knows how to render
+//! the states specific to a searchable pick list, like an empty result set.
+use iced_native::alignment;
+use iced_native::event::{self, Event};
+use iced_native::layout;
+use iced_native::mouse;
+use iced_native::overlay;
+use iced_native::renderer;
+use iced_native::text::{self, Text};
+use iced_native::touch;
+use iced_native::widget::container::{self, Container};
+use iced_native::widget::scrollable::{self, Scrollable};
+use iced_native::widget::{tree, Tree};
+use iced_native::{
+    Background, Clipboard, Color, Element, Layout, Length, Padding, Point, Rectangle, Shell, Size,
+    Widget,
+};
+use iced_style::pick_list;
+
+use crate::normalize;
+
+use std::cell::Cell;
+use std::ops::Range;
+
+pub use iced_style::menu::StyleSheet;
+
+/// What the dropdown of a [`PickList`](crate::PickList) shows when there are
+/// no options to pick from.
+#[allow(missing_debug_implementations)]
+pub enum Empty<'a, Message, Renderer> {
+    /// A line of text, drawn with the placeholder color of the pick list.
+    Text(String),
+    /// An arbitrary, non-interactive [`Element`].
+    Element(&'a Element<'a, Message, Renderer>),
+}
+
+/// A list of selectable options.
+#[allow(missing_debug_implementations)]
+pub struct Menu<'a, T, Message, Renderer>
+where
+    Renderer: text::Renderer,
+    Renderer::Theme: pick_list::StyleSheet,
+{
+    state: &'a mut State,
+    options: &'a [T],
+    rows: Option<&'a [Row]>,
+    hovered_option: &'a mut Option<usize>,
+    last_selection: &'a mut Option<T>,
+    last_completion: &'a mut Option<String>,
+    highlights: Option<Box<dyn Fn(&str) -> Vec<Range<usize>> + 'a>>,
+    empty: Option<Empty<'a, Message, Renderer>>,
+    width: Length,
+    height: Length,
+    max_visible_items: Option<usize>,
+    placement: Placement,
+    alignment: alignment::Horizontal,
+    padding: Padding,
+    text_size: Option<u16>,
+    font: Renderer::Font,
+    style: <Renderer::Theme as pick_list::StyleSheet>::Style,
+}
+
+impl<'a, T, Message, Renderer> Menu<'a, T, Message, Renderer>
+where
+    T: ToString + Clone,
+    Message: 'a,
+    Renderer: text::Renderer + 'a,
+    Renderer::Theme:
+        StyleSheet + pick_list::StyleSheet + container::StyleSheet + scrollable::StyleSheet,
+    <Renderer::Theme as StyleSheet>::Style: From<<Renderer::Theme as pick_list::StyleSheet>::Style>,
+{
+    /// Creates a new [`Menu`] with the given [`State`], a list of options, and
+    /// the places to store the picked option or completion in.
+    pub fn new(
+        state: &'a mut State,
+        options: &'a [T],
+        hovered_option: &'a mut Option<usize>,
+        last_selection: &'a mut Option<T>,
+        last_completion: &'a mut Option<String>,
+    ) -> Self {
+        Menu {
+            state,
+            options,
+            hovered_option,
+            last_selection,
+            last_completion,
+            highlights: None,
+            rows: None,
+            empty: None,
+            width: Length::Shrink,
+            height: Length::Shrink,
+            max_visible_items: None,
+            placement: Placement::Auto,
+            alignment: alignment::Horizontal::Left,
+            padding: Padding::ZERO,
+            text_size: None,
+            font: Default::default(),
+            style: Default::default(),
+        }
+    }
+
+    /// Sets the [`Row`]s of the [`Menu`], which reorder the options and
+    /// divide them into sections.
+    ///
+    /// By default, every option is shown in order.
+    pub fn rows(mut self, rows: Option<&'a [Row]>) -> Self {
+        self.rows = rows;
+        self
+    }
+
+    /// Sets the function that returns the byte ranges of a label to
+    /// highlight, e.g. the ones matching the query.
+    pub fn highlights(
+        mut self,
+        highlights: Option<Box<dyn Fn(&str) -> Vec<Range<usize>> + 'a>>,
+    ) -> Self {
+        self.highlights = highlights;
+        self
+    }
+
+    /// Sets what the [`Menu`] shows when there are no options.
+    pub fn empty(mut self, empty: Option<Empty<'a, Message, Renderer>>) -> Self {
+        self.empty = empty;
+        self
+    }
+
+    /// Sets the width of the [`Menu`].
+    ///
+    /// [`Length::Fill`] takes all the horizontal space right of the target.
+    pub fn width(mut self, width: Length) -> Self {
+        self.width = width;
+        self
+    }
+
+    /// Sets the height of the [`Menu`].
+    ///
+    /// [`Length::Units`] caps the height of the [`Menu`], while
+    /// [`Length::Fill`] takes all the space available on its side of the
+    /// target.
+    pub fn height(mut self, height: Length) -> Self {
+        self.height = height;
+        self
+    }
+
+    /// Sets the maximum amount of options that are visible at once before
+    /// the [`Menu`] starts scrolling.
+    pub fn max_visible_items(mut self, max_visible_items: Option<usize>) -> Self {
+        self.max_visible_items = max_visible_items;
+        self
+    }
+
+    /// Sets the [`Padding`] of the [`Menu`].
+    pub fn padding<P: Into<Padding>>(mut self, padding: P) -> Self {
+        self.padding = padding.into();
+        self
+    }
+
+    /// Sets the text size of the [`Menu`].
+    pub fn text_size(mut self, text_size: u16) -> Self {
+        self.text_size = Some(text_size);
+        self
+    }
+
+    /// Sets the font of the [`Menu`].
+    pub fn font(mut self, font: Renderer::Font) -> Self {
+        self.font = font;
+        self
+    }
+
+    /// Sets the style of the [`Menu`].
+    pub fn style(
+        mut self,
+        style: impl Into<<Renderer::Theme as pick_list::StyleSheet>::Style>,
+    ) -> Self {
+        self.style = style.into();
+        self
+    }
+
+    /// Sets the vertical [`Placement`] of the [`Menu`].
+    pub fn placement(mut self, placement: Placement) -> Self {
+        self.placement = placement;
+        self
+    }
+
+    /// Sets the horizontal alignment of the [`Menu`] relative to the target.
+    pub fn alignment(mut self, alignment: alignment::Horizontal) -> Self {
+        self.alignment = alignment;
+        self
+    }
+
+    /// Turns the [`Menu`] into an overlay [`Element`] at the given target
+    /// position.
+    ///
+    /// The `target_size` will be used to display the menu either on top
+    /// of the target or under it, depending on the [`Placement`], and to
+    /// align it horizontally.
+    pub fn overlay(
+        self,
+        position: Point,
+        target_size: Size,
+    ) -> overlay::Element<'a, Message, Renderer> {
+        overlay::Element::new(position, Box::new(Overlay::new(self, target_size)))
+    }
+}
+
+/// A row of the dropdown of a [`PickList`](crate::PickList).
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Row {
+    /// A section header that cannot be picked.
+    Header(String),
+    /// The option at the given index.
+    Option(usize),
+    /// A completion of the query, which replaces the query when picked.
+    Completion {
+        /// The label of the completion.
+        label: String,
+        /// The completed query.
+        query: String,
+    },
+}
+
+/// The vertical placement of the dropdown of a [`PickList`](crate::PickList)
+/// relative to it.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum Placement {
+    /// Below the [`PickList`](crate::PickList), unless the dropdown does not
+    /// fit there and there is more space above it.
+    #[default]
+    Auto,
+    /// Always below the [`PickList`](crate::PickList).
+    Below,
+    /// Always above the [`PickList`](crate::PickList).
+    Above,
+}
+
+/// The local state of a [`Menu`].
+#[derive(Debug)]
+pub struct State {
+    tree: Tree,
+    /// The bounds of the scrollable list and of its rows, as last drawn.
+    viewport: Cell<Option<(Rectangle, Rectangle)>>,
+}
+
+impl State {
+    /// Creates a new [`State`] for a [`Menu`].
+    pub fn new() -> Self {
+        Self {
+            tree: Tree::empty(),
+            viewport: Cell::new(None),
+        }
+    }
+
+    /// Scrolls the list of the [`Menu`] the least needed for the row at the
+    /// given index to be fully visible, given the height of every row.
+    pub(crate) fn scroll_to(&mut self, row: usize, row_height: f32) {
+        let Some((bounds, content_bounds)) = self.viewport.get() else {
+            return;
+        };
+
+        let Some(scrollable) = self
+            .tree
+            .children
+            .first_mut()
+            .filter(|child| child.tag == tree::Tag::of::<scrollable::State>())
+        else {
+            return;
+        };
+
+        let scrollable = scrollable.state.downcast_mut::<scrollable::State>();
+        let offset = scrollable.offset(bounds, content_bounds) as f32;
+        let target = scroll_offset(offset, row, row_height, bounds.height);
+
+        scrollable.scroll(offset - target, bounds, content_bounds);
+    }
+}
+
+/// Returns the offset of a list scrolled the least from `offset` for the row
+/// at the given index to be fully visible in a viewport of the given height.
+fn scroll_offset(offset: f32, row: usize, row_height: f32, viewport_height: f32) -> f32 {
+    let top = row as f32 * row_height;
+    let bottom = top + row_height;
+
+    if top < offset {
+        top
+    } else if bottom > offset + viewport_height {
+        (bottom - viewport_height).min(top)
+    } else {
+        offset
+    }
+}
+
+impl Default for State {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+struct Overlay<'a, Message, Renderer>
+where
+    Renderer: iced_native::Renderer,
+    Renderer::Theme: StyleSheet + container::StyleSheet,
+{
+    state: &'a mut State,
+    container: Container<'a, Message, Renderer>,
+    width: Length,
+    target_size: Size,
+    max_visible_items: Option<usize>,
+    placement: Placement,
+    alignment: alignment::Horizontal,
+    padding: Padding,
+    text_size: Option<u16>,
+    style: <Renderer::Theme as StyleSheet>::Style,
+}
+
+impl<'a, Message, Renderer> Overlay<'a, Message, Renderer>
+where
+    Message: 'a,
+    Renderer: text::Renderer + 'a,
+    Renderer::Theme:
+        StyleSheet + pick_list::StyleSheet + container::StyleSheet + scrollable::StyleSheet,
+    <Renderer::Theme as StyleSheet>::Style: From<<Renderer::Theme as pick_list::StyleSheet>::Style>,
+{
+    pub fn new<T>(menu: Menu<'a, T, Message, Renderer>, target_size: Size) -> Self
+    where
+        T: Clone + ToString,
+    {
+        let Menu {
+            state,
+            options,
+            rows,
+            hovered_option,
+            last_selection,
+            last_completion,
+            highlights,
+            empty,
+            width,
+            height,
+            max_visible_items,
+            placement,
+            alignment,
+            padding,
+            font,
+            text_size,
+            style,
+        } = menu;
+
+        let container = match empty {
+            Some(Empty::Element(element)) if options.is_empty() => {
+                Container::new(Borrowed { element })
+            }
+            Some(Empty::Text(label)) if options.is_empty() => Container::new(Placeholder {
+                label,
+                padding,
+                text_size,
+                font: font.clone(),
+                style: style.clone(),
+            }),
+            _ => Container::new(Scrollable::new(List {
+                options,
+                rows,
+                hovered_option,
+                last_selection,
+                last_completion,
+                highlights,
+                font: font.clone(),
+                text_size,
+                padding,
+                style: style.clone(),
+            })),
+        };
+
+        let container = match height {
+            Length::Units(max_height) => container.max_height(u32::from(max_height)),
+            height => container.height(height),
+        };
+
+        state.tree.diff(&container as &dyn Widget<_, _>);
+
+        Self {
+            state,
+            container,
+            width,
+            target_size,
+            max_visible_items,
+            placement,
+            alignment,
+            padding,
+            text_size,
+            style: style.into(),
+        }
+    }
+}
+
+impl<'a, Message, Renderer> iced_native::Overlay<Message, Renderer>
+    for Overlay<'a, Message, Renderer>
+where
+    Renderer: text::Renderer,
+    Renderer::Theme: StyleSheet + container::StyleSheet,
+{
+    fn layout(&self, renderer: &Renderer, bounds: Size, position: Point) -> layout::Node {
+        let space_below = bounds.height - (position.y + self.target_size.height);
+        let space_above = position.y;
+        let space_horizontal = match self.alignment {
+            alignment::Horizontal::Left => bounds.width - position.x,
+            alignment::Horizontal::Center => bounds.width,
+            alignment::Horizontal::Right => position.x + self.target_size.width,
+        };
+
+        let width = match self.width {
+            Length::Fill | Length::FillPortion(_) => space_horizontal,
+            Length::Units(width) => f32::from(width).min(space_horizontal),
+            Length::Shrink => 0.0,
+        };
+
+        let layout = |space: f32| {
+            let max_height = match self.max_visible_items {
+                Some(max_visible_items) => {
+                    let text_size = self.text_size.unwrap_or_else(|| renderer.default_size());
+                    let option_height = f32::from(text_size + self.padding.vertical());
+
+                    space.min(option_height * max_visible_items as f32)
+                }
+                None => space,
+            };
+
+            let limits = layout::Limits::new(Size::ZERO, Size::new(space_horizontal, max_height))
+                .width(Length::Units(width as u16));
+
+            self.container.layout(renderer, &limits)
+        };
+
+        let mut node = layout(space_below.max(space_above));
+
+        let is_below = match self.placement {
+            Placement::Below => true,
+            Placement::Above => false,
+            Placement::Auto => node.size().height <= space_below || space_below >= space_above,
+        };
+
+        let space = if is_below { space_below } else { space_above };
+
+        if node.size().height > space {
+            node = layout(space);
+        }
+
+        let size = node.size();
+
+        let x = match self.alignment {
+            alignment::Horizontal::Left => position.x,
+            alignment::Horizontal::Center => {
+                position.x + (self.target_size.width - size.width) / 2.0
+            }
+            alignment::Horizontal::Right => position.x + self.target_size.width - size.width,
+        };
+
+        let y = if is_below {
+            position.y + self.target_size.height
+        } else {
+            position.y - size.height
+        };
+
+        node.move_to(Point::new(x.min(bounds.width - size.width).max(0.0), y));
+
+        node
+    }
+
+    fn on_event(
+        &mut self,
+        event: Event,
+        layout: Layout<'_>,
+        cursor_position: Point,
+        renderer: &Renderer,
+        clipboard: &mut dyn Clipboard,
+        shell: &mut Shell<'_, Message>,
+    ) -> event::Status {
+        self.container.on_event(
+            &mut self.state.tree,
+            event,
+            layout,
+            cursor_position,
+            renderer,
+            clipboard,
+            shell,
+        )
+    }
+
+    fn mouse_interaction(
+        &self,
+        layout: Layout<'_>,
+        cursor_position: Point,
+        viewport: &Rectangle,
+        renderer: &Renderer,
+    ) -> mouse::Interaction {
+        self.container.mouse_interaction(
+            &self.state.tree,
+            layout,
+            cursor_position,
+            viewport,
+            renderer,
+        )
+    }
+
+    fn draw(
+        &self,
+        renderer: &mut Renderer,
+        theme: &Renderer::Theme,
+        style: &renderer::Style,
+        layout: Layout<'_>,
+        cursor_position: Point,
+    ) {
+        let appearance = StyleSheet::appearance(theme, &self.style);
+        let bounds = layout.bounds();
+
+        renderer.fill_quad(
+            renderer::Quad {
+                bounds: Rectangle {
+                    width: bounds.width - 1.0,
+                    ..bounds
+                },
+                border_color: appearance.border_color,
+                border_width: appearance.border_width,
+                border_radius: appearance.border_radius,
+            },
+            appearance.background,
+        );
+
+        // Remembered to scroll the hovered row into view while navigating
+        // with the keyboard.
+        let scrollable = layout.children().next();
+        let list = scrollable.and_then(|scrollable| scrollable.children().next());
+
+        self.state.viewport.set(
+            scrollable
+                .zip(list)
+                .map(|(scrollable, list)| (scrollable.bounds(), list.bounds())),
+        );
+
+        self.container.draw(
+            &self.state.tree,
+            renderer,
+            theme,
+            style,
+            layout,
+            cursor_position,
+            &bounds,
+        );
+    }
+}
+
+struct List<'a, T, Renderer>
+where
+    Renderer: text::Renderer,
+    Renderer::Theme: pick_list::StyleSheet,
+{
+    options: &'a [T],
+    rows: Option<&'a [Row]>,
+    hovered_option: &'a mut Option<usize>,
+    last_selection: &'a mut Option<T>,
+    last_completion: &'a mut Option<String>,
+    highlights: Option<Box<dyn Fn(&str) -> Vec<Range<usize>> + 'a>>,
+    padding: Padding,
+    text_size: Option<u16>,
+    font: Renderer::Font,
+    style: <Renderer::Theme as pick_list::StyleSheet>::Style,
+}
+
+impl<'a, T, Renderer> List<'a, T, Renderer>
+where
+    Renderer: text::Renderer,
+    Renderer::Theme: pick_list::StyleSheet,
+{
+    fn len(&self) -> usize {
+        self.rows.map_or(self.options.len(), <[Row]>::len)
+    }
+
+    fn row(&self, index: usize) -> Option<RowRef<'_>> {
+        match self.rows {
+            Some(rows) => rows.get(index).map(|row| match row {
+                Row::Header(label) => RowRef::Header(label),
+                Row::Option(index) => RowRef::Option(*index),
+                Row::Completion { label, .. } => RowRef::Completion(label),
+            }),
+            None => (index < self.options.len()).then_some(RowRef::Option(index)),
+        }
+    }
+
+    fn row_at(&self, renderer: &Renderer, bounds: Rectangle, cursor_position: Point) -> usize {
+        let text_size = self.text_size.unwrap_or_else(|| renderer.default_size());
+
+        ((cursor_position.y - bounds.y) / f32::from(text_size + self.padding.vertical())) as usize
+    }
+
+    fn hover(&mut self, renderer: &Renderer, bounds: Rectangle, cursor_position: Point) {
+        let row = self.row_at(renderer, bounds, cursor_position);
+
+        if let Some(RowRef::Option(index)) = self.row(row) {
+            *self.hovered_option = Some(index);
+        }
+    }
+
+    /// Selects the option at the cursor position, if any. Headers cannot be
+    /// picked.
+    fn pick(&mut self, renderer: &Renderer, bounds: Rectangle, cursor_position: Point)
+    where
+        T: Clone,
+    {
+        let row = self.row_at(renderer, bounds, cursor_position);
+
+        if let Some(RowRef::Option(index)) = self.row(row) {
+            if let Some(option) = self.options.get(index) {
+                *self.last_selection = Some(option.clone());
+            }
+        }
+    }
+
+    /// Picks the completion at the cursor position, if any.
+    fn complete(&mut self, renderer: &Renderer, bounds: Rectangle, cursor_position: Point) -> bool {
+        let row = self.row_at(renderer, bounds, cursor_position);
+
+        match self.rows.and_then(|rows| rows.get(row)) {
+            Some(Row::Completion { query, .. }) => {
+                *self.last_completion = Some(query.clone());
+
+                true
+            }
+            _ => false,
+        }
+    }
+}
+
+impl<'a, T, Message, Renderer> Widget<Message, Renderer> for List<'a, T, Renderer>
+where
+    T: Clone + ToString,
+    Renderer: text::Renderer,
+    Renderer::Theme: StyleSheet + pick_list::StyleSheet,
+    <Renderer::Theme as StyleSheet>::Style: From<<Renderer::Theme as pick_list::StyleSheet>::Style>,
+{
+    fn width(&self) -> Length {
+        Length::Fill
+    }
+
+    fn height(&self) -> Length {
+        Length::Shrink
+    }
+
+    fn layout(&self, renderer: &Renderer, limits: &layout::Limits) -> layout::Node {
+        use std::f32;
+
+        let limits = limits.width(Length::Fill).height(Length::Shrink);
+        let text_size = self.text_size.unwrap_or_else(|| renderer.default_size());
+
+        let size = {
+            let intrinsic = Size::new(
+                0.0,
+                f32::from(text_size + self.padding.vertical()) * self.len() as f32,
+            );
+
+            limits.resolve(intrinsic)
+        };
+
+        layout::Node::new(size)
+    }
+
+    fn on_event(
+        &mut self,
+        _state: &mut Tree,
+        event: Event,
+        layout: Layout<'_>,
+        cursor_position: Point,
+        renderer: &Renderer,
+        _clipboard: &mut dyn Clipboard,
+        _shell: &mut Shell<'_, Message>,
+    ) -> event::Status {
+        match event {
+            Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left)) => {
+                let bounds = layout.bounds();
+
+                if bounds.contains(cursor_position)
+                    && !self.complete(renderer, bounds, cursor_position)
+                {
+                    self.pick(renderer, bounds, cursor_position);
+                }
+            }
+            Event::Mouse(mouse::Event::CursorMoved { .. }) => {
+                let bounds = layout.bounds();
+
+                if bounds.contains(cursor_position) {
+                    self.hover(renderer, bounds, cursor_position);
+                }
+            }
+            Event::Touch(touch::Event::FingerPressed { .. }) => {
+                let bounds = layout.bounds();
+
+                if bounds.contains(cursor_position)
+                    && !self.complete(renderer, bounds, cursor_position)
+                {
+                    self.hover(renderer, bounds, cursor_position);
+                    self.pick(renderer, bounds, cursor_position);
+                }
+            }
+            _ => {}
+        }
+
+        event::Status::Ignored
+    }
+
+    fn mouse_interaction(
+        &self,
+        _state: &Tree,
+        layout: Layout<'_>,
+        cursor_position: Point,
+        _viewport: &Rectangle,
+        _renderer: &Renderer,
+    ) -> mouse::Interaction {
+        let is_mouse_over = layout.bounds().contains(cursor_position);
+
+        if is_mouse_over {
+            mouse::Interaction::Pointer
+        } else {
+            mouse::Interaction::default()
+        }
+    }
+
+    fn draw(
+        &self,
+        _state: &Tree,
+        renderer: &mut Renderer,
+        theme: &Renderer::Theme,
+        _style: &renderer::Style,
+        layout: Layout<'_>,
+        cursor_position: Point,
+        viewport: &Rectangle,
+    ) {
+        let appearance = StyleSheet::appearance(theme, &self.style.clone().into());
+        let placeholder_color = pick_list::StyleSheet::active(theme, &self.style).placeholder_color;
+        let Background::Color(highlight_color) = appearance.selected_background;
+        let bounds = layout.bounds();
+
+        let text_size = self.text_size.unwrap_or_else(|| renderer.default_size());
+        let option_height = (text_size + self.padding.vertical()) as usize;
+
+        let offset = viewport.y - bounds.y;
+        let start = (offset / option_height as f32) as usize;
+        let end = ((offset + viewport.height) / option_height as f32).ceil() as usize;
+
+        for i in start..end.min(self.len()) {
+            let is_option = matches!(self.row(i), Some(RowRef::Option(_)));
+            let (label, is_selected, is_header) = match self.row(i) {
+                Some(RowRef::Header(label)) => (label.to_owned(), false, true),
+                Some(RowRef::Completion(label)) => {
+                    let is_hovered = bounds.contains(cursor_position)
+                        && i == self.row_at(renderer, bounds, cursor_position);
+
+                    (label.to_owned(), is_hovered, false)
+                }
+                Some(RowRef::Option(index)) => match self.options.get(index) {
+                    Some(option) => (
+                        option.to_string(),
+                        *self.hovered_option == Some(index),
+                        false,
+                    ),
+                    None => continue,
+                },
+                None => continue,
+            };
+
+            let bounds = Rectangle {
+                x: bounds.x,
+                y: bounds.y + (option_height * i) as f32,
+                width: bounds.width,
+                height: f32::from(text_size + self.padding.vertical()),
+            };
+
+            if is_selected {
+                renderer.fill_quad(
+                    renderer::Quad {
+                        bounds,
+                        border_color: Color::TRANSPARENT,
+                        border_width: 0.0,
+                        border_radius: appearance.border_radius,
+                    },
+                    appearance.selected_background,
+                );
+            }
+
+            let color = if is_header {
+                placeholder_color
+            } else if is_selected {
+                appearance.selected_text_color
+            } else {
+                appearance.text_color
+            };
+
+            let highlights = match &self.highlights {
+                Some(highlights) if is_option && !is_selected => highlights(&label),
+                _ => Vec::new(),
+            };
+
+            let mut x = bounds.x + self.padding.left as f32;
+
+            for (range, is_highlighted) in segments(&label, highlights) {
+                let content = &label[range];
+
+                renderer.fill_text(Text {
+                    content,
+                    bounds: Rectangle {
+                        x,
+                        y: bounds.center_y(),
+                        width: f32::INFINITY,
+                        ..bounds
+                    },
+                    size: f32::from(text_size),
+                    font: self.font.clone(),
+                    color: if is_highlighted {
+                        highlight_color
+                    } else {
+                        color
+                    },
+                    horizontal_alignment: alignment::Horizontal::Left,
+                    vertical_alignment: alignment::Vertical::Center,
+                });
+
+                let (width, _) = renderer.measure(
+                    content,
+                    text_size,
+                    self.font.clone(),
+                    Size::new(f32::INFINITY, f32::INFINITY),
+                );
+
+                x += width;
+            }
+        }
+    }
+}
+
+impl<'a, T, Message, Renderer> From<List<'a, T, Renderer>> for Element<'a, Message, Renderer>
+where
+    T: ToString + Clone,
+    Message: 'a,
+    Renderer: 'a + text::Renderer,
+    Renderer::Theme: StyleSheet + pick_list::StyleSheet,
+    <Renderer::Theme as StyleSheet>::Style: From<<Renderer::Theme as pick_list::StyleSheet>::Style>,
+{
+    fn from(list: List<'a, T, Renderer>) -> Self {
+        Element::new(list)
+    }
+}
+
+/// Splits a label into consecutive segments, marking the highlighted ones.
+///
+/// Overlapping highlights are merged, and highlights that do not fall on
+/// character boundaries are ignored.
+fn segments(label: &str, highlights: Vec<Range<usize>>) -> Vec<(Range<usize>, bool)> {
+    let highlights = normalize::merge(highlights);
+
+    let mut segments = Vec::with_capacity(highlights.len() * 2 + 1);
+    let mut end = 0;
+
+    for range in highlights {
+        if range.is_empty() || label.get(range.clone()).is_none() {
+            continue;
+        }
+
+        if range.start > end {
+            segments.push((end..range.start, false));
+        }
+
+        end = range.end;
+        segments.push((range, true));
+    }
+
+    if end < label.len() || segments.is_empty() {
+        segments.push((end..label.len(), false));
+    }
+
+    segments
+}
+
+/// A borrowed [`Row`].
+enum RowRef<'a> {
+    Header(&'a str),
+    Option(usize),
+    Completion(&'a str),
+}
+
+/// A single line of text shown in place of an empty [`List`].
+struct Placeholder<Renderer>
+where
+    Renderer: text::Renderer,
+    Renderer::Theme: pick_list::StyleSheet,
+{
+    label: String,
+    padding: Padding,
+    text_size: Option<u16>,
+    font: Renderer::Font,
+    style: <Renderer::Theme as pick_list::StyleSheet>::Style,
+}
+
+impl<Message, Renderer> Widget<Message, Renderer> for Placeholder<Renderer>
+where
+    Renderer: text::Renderer,
+    Renderer::Theme: pick_list::StyleSheet,
+{
+    fn width(&self) -> Length {
+        Length::Fill
+    }
+
+    fn height(&self) -> Length {
+        Length::Shrink
+    }
+
+    fn layout(&self, renderer: &Renderer, limits: &layout::Limits) -> layout::Node {
+        let limits = limits.width(Length::Fill).height(Length::Shrink);
+        let text_size = self.text_size.unwrap_or_else(|| renderer.default_size());
+
+        let intrinsic = Size::new(0.0, f32::from(text_size + self.padding.vertical()));
+
+        layout::Node::new(limits.resolve(intrinsic))
+    }
+
+    fn draw(
+        &self,
+        _state: &Tree,
+        renderer: &mut Renderer,
+        theme: &Renderer::Theme,
+        _style: &renderer::Style,
+        layout: Layout<'_>,
+        _cursor_position: Point,
+        _viewport: &Rectangle,
+    ) {
+        let appearance = pick_list::StyleSheet::active(theme, &self.style);
+        let bounds = layout.bounds();
+
+        renderer.fill_text(Text {
+            content: &self.label,
+            bounds: Rectangle {
+                x: bounds.x + self.padding.left as f32,
+                y: bounds.center_y(),
+                width: f32::INFINITY,
+                ..bounds
+            },
+            size: f32::from(self.text_size.unwrap_or_else(|| renderer.default_size())),
+            font: self.font.clone(),
+            color: appearance.placeholder_color,
+            horizontal_alignment: alignment::Horizontal::Left,
+            vertical_alignment: alignment::Vertical::Center,
+        });
+    }
+}
+
+impl<'a, Message, Renderer> From<Placeholder<Renderer>> for Element<'a, Message, Renderer>
+where
+    Renderer: 'a + text::Renderer,
+    Renderer::Theme: pick_list::StyleSheet,
+{
+    fn from(placeholder: Placeholder<Renderer>) -> Self {
+        Element::new(placeholder)
+    }
+}
+
+/// Displays a borrowed [`Element`] without forwarding any events to it.
+struct Borrowed<'a, Message, Renderer> {
+    element: &'a Element<'a, Message, Renderer>,
+}
+
+impl<'a, Message, Renderer> Widget<Message, Renderer> for Borrowed<'a, Message, Renderer>
+where
+    Renderer: iced_native::Renderer,
+{
+    fn children(&self) -> Vec<Tree> {
+        vec![Tree::new(self.element.as_widget())]
+    }
+
+    fn diff(&self, tree: &mut Tree) {
+        tree.diff_children(std::slice::from_ref(&self.element.as_widget()));
+    }
+
+    fn tag(&self) -> tree::Tag {
+        tree::Tag::stateless()
+    }
+
+    fn width(&self) -> Length {
+        self.element.as_widget().width()
+    }
+
+    fn height(&self) -> Length {
+        self.element.as_widget().height()
+    }
+
+    fn layout(&self, renderer: &Renderer, limits: &layout::Limits) -> layout::Node {
+        self.element.as_widget().layout(renderer, limits)
+    }
+
+    fn mouse_interaction(
+        &self,
+        tree: &Tree,
+        layout: Layout<'_>,
+        cursor_position: Point,
+        viewport: &Rectangle,
+        renderer: &Renderer,
+    ) -> mouse::Interaction {
+        self.element.as_widget().mouse_interaction(
+            &tree.children[0],
+            layout,
+            cursor_position,
+            viewport,
+            renderer,
+        )
+    }
+
+    fn draw(
+        &self,
+        tree: &Tree,
+        renderer: &mut Renderer,
+        theme: &Renderer::Theme,
+        style: &renderer::Style,
+        layout: Layout<'_>,
+        cursor_position: Point,
+        viewport: &Rectangle,
+    ) {
+        self.element.as_widget().draw(
+            &tree.children[0],
+            renderer,
+            theme,
+            style,
+            layout,
+            cursor_position,
+            viewport,
+        )
+    }
+}
+
+impl<'a, Message, Renderer> From<Borrowed<'a, Message, Renderer>> for Element<'a, Message, Renderer>
+where
+    Message: 'a,
+    Renderer: 'a + iced_native::Renderer,
+{
+    fn from(borrowed: Borrowed<'a, Message, Renderer>) -> Self {
+        Element::new(borrowed)
+    }
+}
+
+#[cfg(test)]
+#[allow(clippy::single_range_in_vec_init)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn splits_labels_into_segments() {
+        assert_eq!(
+            segments("Dark theme", vec![0..2, 5..7]),
+            [(0..2, true), (2..5, false), (5..7, true), (7..10, false)]
+        );
+        assert_eq!(segments("Dark", vec![0..4]), [(0..4, true)]);
+        assert_eq!(segments("Dark", Vec::new()), [(0..4, false)]);
+        assert_eq!(segments("", Vec::new()), [(0..0, false)]);
+    }
+
+    #[test]
+    fn merges_overlapping_and_out_of_order_highlights() {
+        assert_eq!(
+            segments("Dark theme", vec![5..7, 0..3, 1..2, 2..4]),
+            [(0..4, true), (4..5, false), (5..7, true), (7..10, false)]
+        );
+    }
+
+    #[test]
+    fn ignores_invalid_highlights() {
+        // `é` spans the bytes 1..3.
+        assert_eq!(
+            segments("héllo", vec![0..2, 3..4]),
+            [(0..3, false), (3..4, true), (4..6, false)]
+        );
+        assert_eq!(segments("Dark", vec![2..2, 3..10]), [(0..4, false)]);
+    }
+
+    #[test]
+    fn keeps_visible_rows_in_place() {
+        // Rows of 10 pixels in a viewport of 3 rows, scrolled by one row.
+        assert_eq!(scroll_offset(10.0, 1, 10.0, 30.0), 10.0);
+        assert_eq!(scroll_offset(10.0, 3, 10.0, 30.0), 10.0);
+    }
+
+    #[test]
+    fn scrolls_up_to_rows_above() {
+        assert_eq!(scroll_offset(10.0, 0, 10.0, 30.0), 0.0);
+        assert_eq!(scroll_offset(50.0, 2, 10.0, 30.0), 20.0);
+    }
+
+    #[test]
+    fn scrolls_down_to_rows_below() {
+        assert_eq!(scroll_offset(0.0, 3, 10.0, 30.0), 10.0);
+        assert_eq!(scroll_offset(0.0, 9, 10.0, 30.0), 70.0);
+    }
+
+    #[test]
+    fn scrolls_to_partially_visible_rows() {
+        assert_eq!(scroll_offset(5.0, 0, 10.0, 30.0), 0.0);
+        assert_eq!(scroll_offset(5.0, 3, 10.0, 30.0), 10.0);
+    }
+
+    #[test]
+    fn shows_the_top_of_rows_taller_than_the_viewport() {
+        assert_eq!(scroll_offset(0.0, 2, 10.0, 5.0), 20.0);
+    }
+}
@@ -0,0 +1,251 @@
+//! Normalize labels and queries so they match regardless of diacritics,
+//! compatibility forms and case.
+use crate::matcher::Matcher;
+
+use std::ops::Range;
+
+use unicode_normalization::char::is_combining_mark;
+use unicode_normalization::UnicodeNormalization;
+use unicode_segmentation::UnicodeSegmentation;
+
+/// Normalizes a text for matching.
+///
+/// The text is decomposed into its compatibility form (NFKD), stripped of
+/// combining marks and case folded. For instance, `Café`, `ＣＡＦＥ` and `cafe`
+/// all normalize to `cafe`, and `Straße` normalizes to `strasse`.
+pub fn normalize(text: &str) -> String {
+    Folded::normalized(text).text
+}
+
+/// A text folded for matching, which remembers the range of the original
+/// text that each of its bytes comes from.
+#[derive(Debug, Clone, Default)]
+pub(crate) struct Folded {
+    text: String,
+    origins: Vec<Range<usize>>,
+}
+
+impl Folded {
+    /// Lowercases every character of the text.
+    pub(crate) fn lowercase(text: &str) -> Self {
+        let mut folded = Self::default();
+
+        for (index, c) in text.char_indices() {
+            for lowercase in c.to_lowercase() {
+                folded.push(lowercase, index..index + c.len_utf8());
+            }
+        }
+
+        folded
+    }
+
+    /// Normalizes every grapheme of the text, like [`normalize`].
+    pub(crate) fn normalized(text: &str) -> Self {
+        let mut folded = Self::default();
+
+        for (index, grapheme) in text.grapheme_indices(true) {
+            let origin = index..index + grapheme.len();
+
+            for c in grapheme.nfkd().filter(|c| !is_combining_mark(*c)) {
+                for lowercase in c.to_lowercase() {
+                    // Full case folding, where it differs from lowercasing.
+                    match lowercase {
+                        'ß' => {
+                            folded.push('s', origin.clone());
+                            folded.push('s', origin.clone());
+                        }
+                        'ς' => folded.push('σ', origin.clone()),
+                        lowercase => folded.push(lowercase, origin.clone()),
+                    }
+                }
+            }
+        }
+
+        folded
+    }
+
+    fn push(&mut self, c: char, origin: Range<usize>) {
+        self.text.push(c);
+        self.origins
+            .extend(std::iter::repeat_n(origin, c.len_utf8()));
+    }
+
+    /// Returns the folded text.
+    pub(crate) fn as_str(&self) -> &str {
+        &self.text
+    }
+
+    /// Maps byte ranges of the folded text back to merged byte ranges of the
+    /// original text, dropping empty ones.
+    pub(crate) fn origins(
+        &self,
+        ranges: impl IntoIterator<Item = Range<usize>>,
+    ) -> Vec<Range<usize>> {
+        merge(
+            ranges
+                .into_iter()
+                .filter(|range| !range.is_empty())
+                .filter_map(|range| {
+                    let start = self.origins.get(range.start)?.start;
+                    let end = self.origins.get(range.end.checked_sub(1)?)?.end;
+
+                    Some(start..end)
+                })
+                .collect(),
+        )
+    }
+}
+
+/// Sorts byte ranges and merges the ones that overlap or touch.
+pub(crate) fn merge(mut ranges: Vec<Range<usize>>) -> Vec<Range<usize>> {
+    ranges.sort_by_key(|range| range.start);
+
+    let mut merged: Vec<Range<usize>> = Vec::with_capacity(ranges.len());
+
+    for range in ranges {
+        match merged.last_mut() {
+            Some(last) if range.start <= last.end => last.end = last.end.max(range.end),
+            _ => merged.push(range),
+        }
+    }
+
+    merged
+}
+
+/// A [`Matcher`] that normalizes both the query and the labels before
+/// matching them with another [`Matcher`], as [`normalize`] does.
+///
+/// Created with [`Matcher::normalized`].
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct Normalized<M> {
+    matcher: M,
+}
+
+impl<M: Matcher> Normalized<M> {
+    /// Normalizes the query and labels matched by the given [`Matcher`].
+    pub fn new(matcher: M) -> Self {
+        Self { matcher }
+    }
+}
+
+impl<M: Matcher> Matcher for Normalized<M> {
+    fn score(&self, query: &str, label: &str) -> Option<f32> {
+        self.matcher.score(&normalize(query), &normalize(label))
+    }
+
+    fn highlights(&self, query: &str, label: &str) -> Vec<Range<usize>> {
+        let folded = Folded::normalized(label);
+
+        folded.origins(self.matcher.highlights(&normalize(query), folded.as_str()))
+    }
+
+    fn error(&self, query: &str) -> Option<String> {
+        self.matcher.error(&normalize(query))
+    }
+}
+
+#[cfg(test)]
+#[allow(clippy::single_range_in_vec_init)]
+mod tests {
+    use super::*;
+    use crate::matcher::Substring;
+
+    fn highlights(query: &str, label: &str) -> Vec<Range<usize>> {
+        let ranges = Normalized::new(Substring).highlights(query, label);
+
+        for range in &ranges {
+            assert!(label.get(range.clone()).is_some(), "{range:?} of {label:?}");
+        }
+
+        ranges
+    }
+
+    #[test]
+    fn strips_diacritics() {
+        assert_eq!(normalize("Café"), "cafe");
+        assert_eq!(normalize("Cafe\u{301}"), "cafe");
+        assert_eq!(normalize("Ångström"), "angstrom");
+    }
+
+    #[test]
+    fn folds_case() {
+        assert_eq!(normalize("Straße"), "strasse");
+        assert_eq!(normalize("STRASSE"), "strasse");
+        assert_eq!(normalize("ΟΔΟΣ"), "οδοσ");
+        assert_eq!(normalize("οδος"), "οδοσ");
+    }
+
+    #[test]
+    fn decomposes_compatibility_forms() {
+        assert_eq!(normalize("ＣＡＦＥ"), "cafe");
+        assert_eq!(normalize("ﬁle"), "file");
+        assert_eq!(normalize("①"), "1");
+    }
+
+    #[test]
+    fn matches_normalized() {
+        let matcher = Normalized::new(Substring);
+
+        assert!(matcher.score("cafe", "Le Café").is_some());
+        assert!(matcher.score("café", "CAFE").is_some());
+        assert!(matcher.score("strasse", "Straße").is_some());
+        assert!(matcher.score("cafe", "ＣＡＦＥ").is_some());
+    }
+
+    #[test]
+    fn highlights_precomposed_graphemes() {
+        assert_eq!(highlights("cafe", "Le Café"), [3..8]);
+        assert_eq!(highlights("e", "Café"), [3..5]);
+    }
+
+    #[test]
+    fn highlights_decomposed_graphemes() {
+        // The combining accent belongs to the highlighted grapheme.
+        assert_eq!(highlights("e", "Cafe\u{301}"), [3..6]);
+        assert_eq!(highlights("caf", "Cafe\u{301}"), [0..3]);
+    }
+
+    #[test]
+    fn highlights_expanded_graphemes() {
+        assert_eq!(highlights("strasse", "Straße"), [0..7]);
+        assert_eq!(highlights("sse", "Straße"), [4..7]);
+        // Half of `ß` highlights all of it.
+        assert_eq!(highlights("as", "Straße"), [3..6]);
+    }
+
+    #[test]
+    fn highlights_full_width_forms() {
+        assert_eq!(highlights("af", "ＣＡＦＥ"), [3..9]);
+        assert_eq!(highlights("fi", "ﬁle"), [0..3]);
+        assert_eq!(highlights("f", "ﬁle"), [0..3]);
+    }
+
+    #[test]
+    fn maps_origins() {
+        let folded = Folded::normalized("Straße");
+
+        assert_eq!(folded.as_str(), "strasse");
+        assert_eq!(folded.origins([0..1, 1..3]), [0..3]);
+        assert_eq!(folded.origins([4..5]), [4..6]);
+        assert_eq!(folded.origins([5..6, 6..7]), [4..7]);
+        // Inside `ß`, an empty range would otherwise widen to all of it.
+        assert!(folded.origins([3..3, 5..5, 7..8]).is_empty());
+    }
+
+    #[test]
+    fn maps_lowercase_origins() {
+        let folded = Folded::lowercase("İx");
+
+        // `İ` lowercases to `i` and a combining dot.
+        assert_eq!(folded.as_str(), "i\u{307}x");
+        assert_eq!(folded.origins([0..1]), [0..2]);
+        assert_eq!(folded.origins([3..4]), [2..3]);
+    }
+
+    #[test]
+    fn merges_ranges() {
+        assert_eq!(merge(vec![4..6, 0..2, 1..3, 6..7]), [0..3, 4..7]);
+        assert_eq!(merge(vec![0..5, 1..2]), [0..5]);
+        assert!(merge(Vec::new()).is_empty());
+    }
+}
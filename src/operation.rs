@@ -0,0 +1,162 @@
+//! Operate on a [`PickList`](crate::PickList) identified by its [`Id`].
+//!
+//! The [`Operation`] trait of `iced_native` only knows about focusable and
+//! scrollable widgets. The operations in this module hand their action to the
+//! targeted [`PickList`](crate::PickList) while the widget tree is traversed,
+//! and may produce a message in return.
+use iced_native::widget::operation::{Operation, Outcome};
+use iced_native::widget::text_input::Id;
+
+use std::any::{Any, TypeId};
+use std::cell::RefCell;
+
+/// An action performed on the [`State`](crate::State) of a
+/// [`PickList`](crate::PickList).
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(crate) enum Action {
+    MoveCursorToFront,
+    MoveCursorToEnd,
+    MoveCursorTo(usize),
+    SelectAll,
+    Open,
+    Close,
+    Preselect(usize),
+    SetQuery(String),
+}
+
+thread_local! {
+    static PENDING: RefCell<Option<(Id, Action, TypeId)>> = const { RefCell::new(None) };
+    static OUTPUT: RefCell<Option<Box<dyn Any>>> = const { RefCell::new(None) };
+}
+
+/// Returns the [`Action`] of the [`Operation`] that is currently traversing
+/// the widget tree, if it targets the given [`Id`].
+pub(crate) fn pending(id: &Id) -> Option<Action> {
+    PENDING.with(|pending| match &*pending.borrow() {
+        Some((target, action, _)) if target == id => Some(action.clone()),
+        _ => None,
+    })
+}
+
+/// Returns whether the [`Operation`] that is currently traversing the widget
+/// tree produces messages of the given type.
+///
+/// It does not when the [`PickList`](crate::PickList) is inside a mapped
+/// [`Element`](iced_native::Element), since mapping an [`Operation`] does not
+/// map the message it produces.
+pub(crate) fn produces<Message: 'static>() -> bool {
+    PENDING.with(|pending| {
+        matches!(&*pending.borrow(), Some((_, _, message)) if *message == TypeId::of::<Message>())
+    })
+}
+
+/// Hands a message produced by the targeted [`PickList`](crate::PickList)
+/// back to the [`Operation`] that is currently traversing the widget tree.
+pub(crate) fn output<Message: 'static>(message: Message) {
+    OUTPUT.with(|output| *output.borrow_mut() = Some(Box::new(message)));
+}
+
+struct Perform {
+    target: Id,
+    action: Action,
+    output: RefCell<Option<Box<dyn Any>>>,
+}
+
+impl<T: 'static> Operation<T> for Perform {
+    fn container(
+        &mut self,
+        _id: Option<&iced_native::widget::Id>,
+        operate_on_children: &mut dyn FnMut(&mut dyn Operation<T>),
+    ) {
+        let previous = PENDING.with(|pending| {
+            pending.replace(Some((
+                self.target.clone(),
+                self.action.clone(),
+                TypeId::of::<T>(),
+            )))
+        });
+
+        operate_on_children(self);
+
+        PENDING.with(|pending| *pending.borrow_mut() = previous);
+
+        if let Some(output) = OUTPUT.with(|output| output.borrow_mut().take()) {
+            *self.output.borrow_mut() = Some(output);
+        }
+    }
+
+    fn finish(&self) -> Outcome<T> {
+        match self
+            .output
+            .borrow_mut()
+            .take()
+            .map(|output| output.downcast::<T>())
+        {
+            Some(Ok(message)) => Outcome::Some(*message),
+            _ => Outcome::None,
+        }
+    }
+}
+
+fn perform<T: 'static>(target: Id, action: Action) -> impl Operation<T> {
+    Perform {
+        target,
+        action,
+        output: RefCell::new(None),
+    }
+}
+
+/// Produces an [`Operation`] that moves the cursor of the
+/// [`PickList`](crate::PickList) with the given [`Id`] to the front.
+pub fn move_cursor_to_front<T: 'static>(target: Id) -> impl Operation<T> {
+    perform(target, Action::MoveCursorToFront)
+}
+
+/// Produces an [`Operation`] that moves the cursor of the
+/// [`PickList`](crate::PickList) with the given [`Id`] to the end.
+pub fn move_cursor_to_end<T: 'static>(target: Id) -> impl Operation<T> {
+    perform(target, Action::MoveCursorToEnd)
+}
+
+/// Produces an [`Operation`] that moves the cursor of the
+/// [`PickList`](crate::PickList) with the given [`Id`] to the given position.
+pub fn move_cursor_to<T: 'static>(target: Id, position: usize) -> impl Operation<T> {
+    perform(target, Action::MoveCursorTo(position))
+}
+
+/// Produces an [`Operation`] that selects all the text of the
+/// [`PickList`](crate::PickList) with the given [`Id`].
+pub fn select_all<T: 'static>(target: Id) -> impl Operation<T> {
+    perform(target, Action::SelectAll)
+}
+
+/// Produces an [`Operation`] that opens the dropdown of the
+/// [`PickList`](crate::PickList) with the given [`Id`] and focuses it.
+pub fn open<T: 'static>(target: Id) -> impl Operation<T> {
+    perform(target, Action::Open)
+}
+
+/// Produces an [`Operation`] that closes the dropdown of the
+/// [`PickList`](crate::PickList) with the given [`Id`] and unfocuses it.
+pub fn close<T: 'static>(target: Id) -> impl Operation<T> {
+    perform(target, Action::Close)
+}
+
+/// Produces an [`Operation`] that highlights the option at the given index in
+/// the dropdown of the [`PickList`](crate::PickList) with the given [`Id`],
+/// without selecting it.
+pub fn preselect<T: 'static>(target: Id, index: usize) -> impl Operation<T> {
+    perform(target, Action::Preselect(index))
+}
+
+/// Produces an [`Operation`] that sets the query of the
+/// [`PickList`](crate::PickList) with the given [`Id`].
+///
+/// Since the query is owned by the application, the operation finishes with
+/// the `on_change` message of the [`PickList`](crate::PickList). Inside a
+/// mapped [`Element`](iced_native::Element), the operation cannot produce
+/// that message, so the [`PickList`](crate::PickList) produces it with the
+/// next event it processes instead.
+pub fn set_query<T: 'static>(target: Id, query: String) -> impl Operation<T> {
+    perform(target, Action::SetQuery(query))
+}
@@ -0,0 +1,190 @@
+//! Match labels against regular expressions.
+use crate::matcher::{Fuzzy, Matcher};
+
+use std::cell::RefCell;
+use std::ops::Range;
+
+/// A [`Matcher`] that treats queries starting with `/` as regular
+/// expressions, and matches any other query with another [`Matcher`].
+///
+/// Regular expressions ignore case. While the query is not a valid regular
+/// expression, the last valid one keeps matching, so the options do not
+/// disappear while the pattern is being typed. [`Matcher::error`] reports
+/// why the query is invalid.
+#[derive(Debug, Default)]
+pub struct Regex<M = Fuzzy> {
+    matcher: M,
+    always: bool,
+    cache: RefCell<Cache>,
+}
+
+#[derive(Debug, Default)]
+struct Cache {
+    query: String,
+    error: Option<String>,
+    last_valid: Option<regex::Regex>,
+}
+
+impl<M: Matcher> Regex<M> {
+    /// Creates a new [`Regex`] matcher that matches queries without the `/`
+    /// prefix with the given [`Matcher`].
+    pub fn new(matcher: M) -> Self {
+        Self {
+            matcher,
+            always: false,
+            cache: RefCell::new(Cache::default()),
+        }
+    }
+
+    /// Sets whether every query is a regular expression, without the `/`
+    /// prefix.
+    pub fn always(mut self, always: bool) -> Self {
+        self.always = always;
+        self
+    }
+
+    /// Returns the regular expression of the query, if it is one.
+    fn pattern<'a>(&self, query: &'a str) -> Option<&'a str> {
+        if self.always {
+            Some(query)
+        } else {
+            query.strip_prefix('/')
+        }
+    }
+
+    /// Compiles the pattern unless it was already compiled for the previous
+    /// query.
+    fn compile(&self, query: &str, pattern: &str) {
+        let mut cache = self.cache.borrow_mut();
+
+        if cache.query == query {
+            return;
+        }
+
+        cache.query = query.to_owned();
+
+        match regex::RegexBuilder::new(pattern)
+            .case_insensitive(true)
+            .build()
+        {
+            Ok(regex) => {
+                cache.error = None;
+                cache.last_valid = Some(regex);
+            }
+            Err(error) => cache.error = Some(error.to_string()),
+        }
+    }
+}
+
+impl<M: Matcher> Matcher for Regex<M> {
+    fn score(&self, query: &str, label: &str) -> Option<f32> {
+        let Some(pattern) = self.pattern(query) else {
+            return self.matcher.score(query, label);
+        };
+
+        self.compile(query, pattern);
+
+        let cache = self.cache.borrow();
+
+        let Some(regex) = &cache.last_valid else {
+            return Some(0.0);
+        };
+
+        let found = regex.find(label)?;
+        let start = label[..found.start()].chars().count();
+        let coverage = found.as_str().chars().count() as f32 / label.chars().count().max(1) as f32;
+
+        Some(1.0 / (1.0 + start as f32) + coverage)
+    }
+
+    fn highlights(&self, query: &str, label: &str) -> Vec<Range<usize>> {
+        let Some(pattern) = self.pattern(query) else {
+            return self.matcher.highlights(query, label);
+        };
+
+        self.compile(query, pattern);
+
+        match &self.cache.borrow().last_valid {
+            Some(regex) => regex
+                .find_iter(label)
+                .map(|found| found.range())
+                .filter(|range| !range.is_empty())
+                .collect(),
+            None => Vec::new(),
+        }
+    }
+
+    fn error(&self, query: &str) -> Option<String> {
+        let Some(pattern) = self.pattern(query) else {
+            return self.matcher.error(query);
+        };
+
+        self.compile(query, pattern);
+
+        self.cache.borrow().error.clone()
+    }
+}
+
+#[cfg(test)]
+#[allow(clippy::single_range_in_vec_init)]
+mod tests {
+    use super::*;
+    use crate::matcher::Substring;
+
+    #[test]
+    fn matches_queries_with_the_prefix_as_regular_expressions() {
+        let regex = Regex::new(Substring);
+
+        assert!(regex.score("/^c.t$", "Cat").is_some());
+        assert_eq!(regex.score("/^c.t$", "Cart"), None);
+        assert_eq!(regex.highlights("/a.", "Cart"), [1..3]);
+    }
+
+    #[test]
+    fn matches_other_queries_with_the_matcher() {
+        let regex = Regex::new(Substring);
+
+        assert_eq!(regex.score("c.t", "Cat"), None);
+        assert_eq!(regex.score("ca", "Cat"), Substring.score("ca", "Cat"));
+        assert_eq!(regex.error("(("), None);
+    }
+
+    #[test]
+    fn always_matches_regular_expressions() {
+        let regex = Regex::new(Substring).always(true);
+
+        assert!(regex.score("^c.t$", "cat").is_some());
+        assert_eq!(regex.score("/^c.t$", "cat"), None);
+    }
+
+    #[test]
+    fn reports_invalid_patterns() {
+        let regex = Regex::new(Substring);
+
+        assert_eq!(regex.error("/ca"), None);
+        assert!(regex.error("/ca(").is_some());
+        assert_eq!(regex.error("/ca(t)"), None);
+    }
+
+    #[test]
+    fn keeps_the_last_valid_pattern() {
+        let regex = Regex::new(Substring);
+
+        assert!(regex.score("/^ca", "Cat").is_some());
+        assert_eq!(regex.score("/^ca", "Dog"), None);
+
+        // While `(` is typed, `^ca` keeps matching.
+        assert!(regex.score("/^ca(", "Cat").is_some());
+        assert_eq!(regex.score("/^ca(", "Dog"), None);
+        assert_eq!(regex.highlights("/^ca(", "Cat"), [0..2]);
+        assert!(regex.error("/^ca(").is_some());
+    }
+
+    #[test]
+    fn matches_everything_without_a_valid_pattern() {
+        let regex = Regex::new(Substring);
+
+        assert_eq!(regex.score("/(", "Cat"), Some(0.0));
+        assert!(regex.highlights("/(", "Cat").is_empty());
+    }
+}
@@ -0,0 +1,378 @@
+//! Parse extended queries with exact, prefix, suffix and negated terms.
+use crate::matcher::{Fuzzy, Matcher, Substring};
+use crate::normalize::{self, Folded};
+
+use std::cell::RefCell;
+use std::ops::Range;
+
+/// The kind of a [`Term`] of a [`Query`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TermKind {
+    /// Matches labels with the [`Matcher`] of the [`Extended`] query, e.g.
+    /// `sbtrkt`.
+    Fuzzy,
+    /// Matches labels that contain the term, e.g. `'wild`.
+    Exact,
+    /// Matches labels that start with the term, e.g. `^music`.
+    Prefix,
+    /// Matches labels that end with the term, e.g. `.mp3$`.
+    Suffix,
+    /// Matches labels that are equal to the term, e.g. `^core$`.
+    Equal,
+}
+
+/// A single term of a [`Query`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Term {
+    /// The text of the term, without its operators.
+    pub text: String,
+    /// The [`TermKind`] of the term.
+    pub kind: TermKind,
+    /// Whether the term excludes the labels it matches, e.g. `!fire`.
+    pub negated: bool,
+}
+
+/// A parsed extended query, in the style of `fzf`.
+///
+/// Terms separated by spaces must all match, while terms separated by `|`
+/// match if any of them does. Every term may use these operators:
+///
+/// | Term      | Matches labels that                 |
+/// |-----------|-------------------------------------|
+/// | `sbtrkt`  | fuzzy match `sbtrkt`                |
+/// | `'wild`   | contain `wild`                      |
+/// | `^music`  | start with `music`                  |
+/// | `.mp3$`   | end with `.mp3`                     |
+/// | `^core$`  | are exactly `core`                  |
+/// | `!fire`   | do not contain `fire`               |
+/// | `!^music` | do not start with `music`           |
+/// | `!.mp3$`  | do not end with `.mp3`              |
+///
+/// Matching ignores case. For instance, `^core go$ | rb$ | !test` matches
+/// labels that start with `core` and either end with `go` or `rb` or do not
+/// contain `test`.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Query {
+    groups: Vec<Vec<Term>>,
+}
+
+impl Query {
+    /// Parses an extended query.
+    pub fn parse(query: &str) -> Self {
+        let mut groups: Vec<Vec<Term>> = Vec::new();
+        let mut is_alternative = false;
+
+        for token in query.split_whitespace() {
+            if token == "|" {
+                is_alternative = !groups.is_empty();
+                continue;
+            }
+
+            let Some(term) = Term::parse(token) else {
+                continue;
+            };
+
+            match groups.last_mut() {
+                Some(group) if is_alternative => group.push(term),
+                _ => groups.push(vec![term]),
+            }
+
+            is_alternative = false;
+        }
+
+        Self { groups }
+    }
+
+    /// Returns whether the [`Query`] has no terms and therefore matches every
+    /// label.
+    pub fn is_empty(&self) -> bool {
+        self.groups.is_empty()
+    }
+
+    /// Returns the groups of the [`Query`]. All groups must match a label,
+    /// while any of the [`Term`]s of a group may.
+    pub fn groups(&self) -> &[Vec<Term>] {
+        &self.groups
+    }
+
+    /// Returns the score of the `label` for the [`Query`], using `matcher`
+    /// for its fuzzy terms, or `None` if it does not match.
+    pub fn score(&self, matcher: &impl Matcher, label: &str) -> Option<f32> {
+        let lowercase = label.to_lowercase();
+
+        self.groups.iter().try_fold(0.0, |total, group| {
+            let score = group
+                .iter()
+                .filter_map(|term| term.score(matcher, label, &lowercase))
+                .max_by(f32::total_cmp)?;
+
+            Some(total + score)
+        })
+    }
+
+    /// Returns the byte ranges of the `label` that match the terms of the
+    /// [`Query`] which are not negated.
+    pub fn highlights(&self, matcher: &impl Matcher, label: &str) -> Vec<Range<usize>> {
+        let lowercase = Folded::lowercase(label);
+
+        normalize::merge(
+            self.groups
+                .iter()
+                .flatten()
+                .filter(|term| !term.negated)
+                .flat_map(|term| term.highlights(matcher, label, &lowercase))
+                .collect(),
+        )
+    }
+}
+
+impl Term {
+    fn parse(token: &str) -> Option<Self> {
+        let (negated, token) = match token.strip_prefix('!') {
+            Some(token) => (true, token),
+            None => (false, token),
+        };
+
+        let (kind, text) = if let Some(text) = token.strip_prefix('\'') {
+            (TermKind::Exact, text)
+        } else {
+            let prefix = token.strip_prefix('^');
+            let is_prefix = prefix.is_some();
+            let text = prefix.unwrap_or(token);
+
+            match (is_prefix, text.strip_suffix('$')) {
+                (true, Some(text)) => (TermKind::Equal, text),
+                (true, None) => (TermKind::Prefix, text),
+                (false, Some(text)) if !text.is_empty() => (TermKind::Suffix, text),
+                // Negated terms never fuzzy match, as `fzf` does.
+                _ if negated => (TermKind::Exact, text),
+                _ => (TermKind::Fuzzy, text),
+            }
+        };
+
+        (!text.is_empty()).then(|| Self {
+            text: text.to_lowercase(),
+            kind,
+            negated,
+        })
+    }
+
+    fn score(&self, matcher: &impl Matcher, label: &str, lowercase: &str) -> Option<f32> {
+        let text = self.text.as_str();
+
+        let score = match self.kind {
+            TermKind::Fuzzy => matcher.score(text, label),
+            TermKind::Exact => Substring.score(text, lowercase),
+            TermKind::Prefix => lowercase.starts_with(text).then_some(1.0),
+            TermKind::Suffix => lowercase.ends_with(text).then_some(1.0),
+            TermKind::Equal => (lowercase == text).then_some(2.0),
+        };
+
+        match (score, self.negated) {
+            (Some(_), true) => None,
+            (None, true) => Some(0.0),
+            (score, false) => score,
+        }
+    }
+
+    fn highlights(
+        &self,
+        matcher: &impl Matcher,
+        label: &str,
+        lowercase: &Folded,
+    ) -> Vec<Range<usize>> {
+        let text = self.text.as_str();
+        let folded = lowercase.as_str();
+
+        let range = match self.kind {
+            TermKind::Fuzzy => return matcher.highlights(text, label),
+            TermKind::Exact => return Substring.highlights(text, label),
+            TermKind::Prefix => folded.starts_with(text).then_some(0..text.len()),
+            TermKind::Suffix => folded
+                .ends_with(text)
+                .then(|| folded.len() - text.len()..folded.len()),
+            TermKind::Equal => (folded == text).then_some(0..folded.len()),
+        };
+
+        lowercase.origins(range)
+    }
+}
+
+/// A [`Matcher`] that parses the query as an extended [`Query`], matching its
+/// fuzzy terms with another [`Matcher`].
+#[derive(Debug, Default)]
+pub struct Extended<M = Fuzzy> {
+    matcher: M,
+    cache: RefCell<Option<(String, Query)>>,
+}
+
+impl<M: Matcher> Extended<M> {
+    /// Creates a new [`Extended`] matcher that matches fuzzy terms with the
+    /// given [`Matcher`].
+    pub fn new(matcher: M) -> Self {
+        Self {
+            matcher,
+            cache: RefCell::new(None),
+        }
+    }
+
+    fn parsed<O>(&self, query: &str, f: impl FnOnce(&Query) -> O) -> O {
+        let mut cache = self.cache.borrow_mut();
+
+        // The query is the same for every label of a filtering pass, so it
+        // is only parsed when it changes.
+        match &*cache {
+            Some((cached, parsed)) if cached == query => f(parsed),
+            _ => f(&cache.insert((query.to_owned(), Query::parse(query))).1),
+        }
+    }
+}
+
+impl<M: Matcher> Matcher for Extended<M> {
+    fn score(&self, query: &str, label: &str) -> Option<f32> {
+        self.parsed(query, |parsed| parsed.score(&self.matcher, label))
+    }
+
+    fn highlights(&self, query: &str, label: &str) -> Vec<Range<usize>> {
+        self.parsed(query, |parsed| parsed.highlights(&self.matcher, label))
+    }
+}
+
+#[cfg(test)]
+#[allow(clippy::single_range_in_vec_init)]
+mod tests {
+    use super::*;
+
+    fn term(text: &str, kind: TermKind, negated: bool) -> Term {
+        Term {
+            text: text.to_owned(),
+            kind,
+            negated,
+        }
+    }
+
+    #[test]
+    fn parses_operators() {
+        assert_eq!(
+            Term::parse("sbtrkt"),
+            Some(term("sbtrkt", TermKind::Fuzzy, false))
+        );
+        assert_eq!(
+            Term::parse("'wild"),
+            Some(term("wild", TermKind::Exact, false))
+        );
+        assert_eq!(
+            Term::parse("^music"),
+            Some(term("music", TermKind::Prefix, false))
+        );
+        assert_eq!(
+            Term::parse(".mp3$"),
+            Some(term(".mp3", TermKind::Suffix, false))
+        );
+        assert_eq!(
+            Term::parse("^core$"),
+            Some(term("core", TermKind::Equal, false))
+        );
+        assert_eq!(
+            Term::parse("!fire"),
+            Some(term("fire", TermKind::Exact, true))
+        );
+        assert_eq!(Term::parse("!^x$"), Some(term("x", TermKind::Equal, true)));
+        assert_eq!(
+            Term::parse("!^music"),
+            Some(term("music", TermKind::Prefix, true))
+        );
+        assert_eq!(
+            Term::parse("!.mp3$"),
+            Some(term(".mp3", TermKind::Suffix, true))
+        );
+    }
+
+    #[test]
+    fn lowercases_terms() {
+        assert_eq!(
+            Term::parse("'Wild"),
+            Some(term("wild", TermKind::Exact, false))
+        );
+    }
+
+    #[test]
+    fn parses_bare_operators() {
+        // A lone `$` has no suffix to match, so it is matched as it is.
+        assert_eq!(Term::parse("$"), Some(term("$", TermKind::Fuzzy, false)));
+        assert_eq!(Term::parse("^"), None);
+        assert_eq!(Term::parse("'"), None);
+        assert_eq!(Term::parse("!"), None);
+        assert_eq!(Term::parse("^$"), None);
+    }
+
+    #[test]
+    fn groups_alternatives() {
+        let query = Query::parse("^core go$ | rb$ | !test");
+
+        assert_eq!(
+            query.groups(),
+            [
+                vec![term("core", TermKind::Prefix, false)],
+                vec![
+                    term("go", TermKind::Suffix, false),
+                    term("rb", TermKind::Suffix, false),
+                    term("test", TermKind::Exact, true),
+                ],
+            ]
+        );
+    }
+
+    #[test]
+    fn ignores_dangling_alternatives() {
+        let expected = [vec![term("a", TermKind::Fuzzy, false)]];
+
+        assert_eq!(Query::parse("| a").groups(), expected);
+        assert_eq!(Query::parse("a |").groups(), expected);
+        assert!(Query::parse("|").is_empty());
+        assert!(Query::parse(" | ").is_empty());
+    }
+
+    #[test]
+    fn ignores_repeated_alternatives() {
+        assert_eq!(
+            Query::parse("a | | b").groups(),
+            [vec![
+                term("a", TermKind::Fuzzy, false),
+                term("b", TermKind::Fuzzy, false),
+            ]]
+        );
+    }
+
+    #[test]
+    fn scores_negated_terms() {
+        let query = Query::parse("!fire");
+
+        assert_eq!(query.score(&Fuzzy, "campfire"), None);
+        assert_eq!(query.score(&Fuzzy, "Campfire"), None);
+        assert_eq!(query.score(&Fuzzy, "water"), Some(0.0));
+    }
+
+    #[test]
+    fn never_fuzzy_matches_negated_terms() {
+        // `fre` fuzzy matches `fire`, but negated terms must contain the text.
+        assert_eq!(Query::parse("!fre").score(&Fuzzy, "fire"), Some(0.0));
+    }
+
+    #[test]
+    fn scores_negated_alternatives() {
+        let query = Query::parse("^core go$ | !test");
+
+        assert!(query.score(&Fuzzy, "core.go").is_some());
+        assert!(query.score(&Fuzzy, "core.rb").is_some());
+        assert_eq!(query.score(&Fuzzy, "core_test.rb"), None);
+        assert_eq!(query.score(&Fuzzy, "lib.go"), None);
+    }
+
+    #[test]
+    fn does_not_highlight_negated_terms() {
+        let query = Query::parse("^core !test");
+
+        assert_eq!(query.highlights(&Fuzzy, "Core.rb"), [0..4]);
+    }
+}
@@ -0,0 +1,220 @@
+//! Keep track of recently used and pinned options.
+use crate::menu::Row;
+
+use std::collections::VecDeque;
+
+/// The recently used and pinned options of a [`PickList`](crate::PickList).
+///
+/// A [`Recent`] list is either owned by the application, which records an
+/// option every time it handles the `on_selected` message of the
+/// [`PickList`](crate::PickList) and may pin options, or tracked by the
+/// [`PickList`](crate::PickList) itself with
+/// [`PickList::track_recent`](crate::PickList::track_recent).
+///
+/// When the query is empty, the [`PickList`](crate::PickList) shows these
+/// options in a separate section at the top of its dropdown; otherwise they
+/// are ranked above the other options.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Recent<T> {
+    capacity: usize,
+    pinned: Vec<T>,
+    recent: VecDeque<T>,
+}
+
+impl<T: PartialEq> Recent<T> {
+    /// The default capacity of a [`Recent`] list.
+    pub const DEFAULT_CAPACITY: usize = 5;
+
+    /// Creates a new [`Recent`] list that remembers up to `capacity`
+    /// recently used options, in addition to the pinned ones.
+    pub fn new(capacity: usize) -> Self {
+        Self {
+            capacity,
+            pinned: Vec::new(),
+            recent: VecDeque::with_capacity(capacity),
+        }
+    }
+
+    /// Records the usage of an option, making it the most recent one.
+    pub fn record(&mut self, option: T) {
+        self.recent.retain(|recent| recent != &option);
+        self.recent.push_front(option);
+        self.recent.truncate(self.capacity);
+    }
+
+    /// Pins an option, which keeps it at the top of the list regardless of
+    /// how recently it was used.
+    pub fn pin(&mut self, option: T) {
+        if !self.is_pinned(&option) {
+            self.pinned.push(option);
+        }
+    }
+
+    /// Unpins an option.
+    pub fn unpin(&mut self, option: &T) {
+        self.pinned.retain(|pinned| pinned != option);
+    }
+
+    /// Returns whether the option is pinned.
+    pub fn is_pinned(&self, option: &T) -> bool {
+        self.pinned.contains(option)
+    }
+
+    /// Forgets all the recently used options, but keeps the pinned ones.
+    pub fn clear(&mut self) {
+        self.recent.clear();
+    }
+
+    /// Returns the pinned options followed by the recently used ones, most
+    /// recent first.
+    pub fn iter(&self) -> impl Iterator<Item = &T> {
+        self.pinned.iter().chain(
+            self.recent
+                .iter()
+                .filter(move |recent| !self.pinned.contains(recent)),
+        )
+    }
+
+    /// Returns the rank of an option in the [`Recent`] list, where `0` is the
+    /// first pinned or, if there are none, the most recently used option.
+    pub fn rank(&self, option: &T) -> Option<usize> {
+        self.iter().position(|recent| recent == option)
+    }
+
+    /// Returns whether there are neither pinned nor recently used options.
+    pub fn is_empty(&self) -> bool {
+        self.pinned.is_empty() && self.recent.is_empty()
+    }
+
+    /// Computes the [`Row`]s of the dropdown, moving the recent options to
+    /// the top. Returns `None` if none of the recent options are available.
+    pub(crate) fn rows(&self, options: &[T], header: Option<&str>) -> Option<Vec<Row>> {
+        let recent: Vec<usize> = self
+            .iter()
+            .filter_map(|recent| options.iter().position(|option| option == recent))
+            .collect();
+
+        if recent.is_empty() {
+            return None;
+        }
+
+        let rest = (0..options.len()).filter(|index| !recent.contains(index));
+
+        Some(
+            header
+                .map(|header| Row::Header(header.to_owned()))
+                .into_iter()
+                .chain(recent.iter().copied().map(Row::Option))
+                .chain(rest.map(Row::Option))
+                .collect(),
+        )
+    }
+}
+
+impl<T: PartialEq> Default for Recent<T> {
+    fn default() -> Self {
+        Self::new(Self::DEFAULT_CAPACITY)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn options(rows: &[Row]) -> Vec<usize> {
+        rows.iter()
+            .filter_map(|row| match row {
+                Row::Option(index) => Some(*index),
+                Row::Header(_) | Row::Completion { .. } => None,
+            })
+            .collect()
+    }
+
+    #[test]
+    fn records_most_recent_first() {
+        let mut recent = Recent::new(3);
+
+        recent.record("a");
+        recent.record("b");
+        recent.record("a");
+
+        assert_eq!(recent.iter().collect::<Vec<_>>(), [&"a", &"b"]);
+    }
+
+    #[test]
+    fn truncates_to_capacity() {
+        let mut recent = Recent::new(2);
+
+        recent.record("a");
+        recent.record("b");
+        recent.record("c");
+
+        assert_eq!(recent.iter().collect::<Vec<_>>(), [&"c", &"b"]);
+        assert_eq!(recent.rank(&"a"), None);
+    }
+
+    #[test]
+    fn keeps_pinned_options_first() {
+        let mut recent = Recent::new(2);
+
+        recent.record("a");
+        recent.record("b");
+        recent.pin("a");
+        recent.pin("c");
+
+        // Pinned options are not shown twice, nor count towards the capacity.
+        assert_eq!(recent.iter().collect::<Vec<_>>(), [&"a", &"c", &"b"]);
+        assert_eq!(recent.rank(&"b"), Some(2));
+
+        recent.clear();
+
+        assert_eq!(recent.iter().collect::<Vec<_>>(), [&"a", &"c"]);
+
+        recent.unpin(&"a");
+
+        assert!(!recent.is_pinned(&"a"));
+        assert_eq!(recent.iter().collect::<Vec<_>>(), [&"c"]);
+    }
+
+    #[test]
+    fn moves_recent_options_to_the_top() {
+        let mut recent = Recent::new(3);
+
+        recent.record("c");
+        recent.record("a");
+
+        let rows = recent.rows(&["a", "b", "c", "d"], None).unwrap();
+
+        assert_eq!(rows, [0, 2, 1, 3].map(Row::Option));
+    }
+
+    #[test]
+    fn adds_a_header() {
+        let mut recent = Recent::new(3);
+
+        recent.record("b");
+        recent.pin("d");
+
+        let rows = recent.rows(&["a", "b", "c", "d"], Some("Recent")).unwrap();
+
+        assert_eq!(rows[0], Row::Header(String::from("Recent")));
+        assert_eq!(options(&rows), [3, 1, 0, 2]);
+    }
+
+    #[test]
+    fn skips_unavailable_options() {
+        let mut recent = Recent::new(3);
+
+        recent.record("z");
+
+        assert_eq!(recent.rows(&["a", "b"], Some("Recent")), None);
+
+        recent.record("b");
+
+        assert_eq!(
+            recent.rows(&["a", "b"], None),
+            Some(vec![Row::Option(1), Row::Option(0)])
+        );
+    }
+}
@@ -0,0 +1,401 @@
+//! A [`PickList`](crate::PickList) that owns its query and filters its options.
+use crate::matcher::{self, Fuzzy, Matcher};
+use crate::menu;
+use crate::StyleSheet;
+
+use iced_native::alignment;
+use iced_native::event::{self, Event};
+use iced_native::layout;
+use iced_native::mouse;
+use iced_native::overlay;
+use iced_native::renderer;
+use iced_native::text;
+use iced_native::widget::operation::Operation;
+use iced_native::widget::text_input::{self, Id, Value};
+use iced_native::widget::{container, scrollable, tree, Tree};
+use iced_native::{Clipboard, Element, Layout, Length, Padding, Point, Rectangle, Shell, Widget};
+use std::borrow::Cow;
+
+/// A [`PickList`](crate::PickList) that keeps track of its query and filters
+/// its options with a [`Matcher`] on its own.
+///
+/// Unlike a [`PickList`](crate::PickList), it only needs the options, the
+/// selected option and the message to produce when an option is selected.
+#[allow(missing_debug_implementations)]
+pub struct SearchablePickList<'a, T, Message, Renderer: text::Renderer>
+where
+    [T]: ToOwned<Owned = Vec<T>>,
+    Renderer::Theme: StyleSheet + text_input::StyleSheet,
+{
+    id: Option<Id>,
+    on_selected: Box<dyn Fn(T) -> Message>,
+    options: Cow<'a, [T]>,
+    selected: Option<T>,
+    matcher: Box<dyn Matcher + 'a>,
+    placeholder: Option<String>,
+    width: Length,
+    padding: Padding,
+    text_size: Option<u16>,
+    font: Renderer::Font,
+    style_sheet: <Renderer::Theme as StyleSheet>::Style,
+    text_style_sheet: <Renderer::Theme as text_input::StyleSheet>::Style,
+}
+
+/// The local state of a [`SearchablePickList`].
+#[derive(Debug)]
+struct State<T> {
+    pick_list: crate::State<T>,
+    query: String,
+    options: Vec<T>,
+}
+
+/// A message of the inner [`PickList`](crate::PickList).
+#[derive(Debug, Clone)]
+enum Internal<T> {
+    Query(String),
+    Selected(T),
+}
+
+impl<'a, T: 'a, Message, Renderer: text::Renderer> SearchablePickList<'a, T, Message, Renderer>
+where
+    T: Clone + ToString + Eq,
+    [T]: ToOwned<Owned = Vec<T>>,
+    Renderer::Theme: StyleSheet + text_input::StyleSheet,
+{
+    /// The default padding of a [`SearchablePickList`].
+    pub const DEFAULT_PADDING: Padding = Padding::new(5);
+
+    /// Creates a new [`SearchablePickList`] with the given list of options, the
+    /// current selected value, and the message to produce when an option is
+    /// selected.
+    pub fn new(
+        options: impl Into<Cow<'a, [T]>>,
+        selected: Option<T>,
+        on_selected: impl Fn(T) -> Message + 'static,
+    ) -> Self {
+        Self {
+            id: None,
+            on_selected: Box::new(on_selected),
+            options: options.into(),
+            selected,
+            matcher: Box::new(Fuzzy),
+            placeholder: None,
+            width: Length::Shrink,
+            padding: Self::DEFAULT_PADDING,
+            text_size: None,
+            font: Default::default(),
+            style_sheet: Default::default(),
+            text_style_sheet: Default::default(),
+        }
+    }
+
+    /// Sets the [`Id`] of the [`SearchablePickList`].
+    pub fn id(mut self, id: Id) -> Self {
+        self.id = Some(id);
+        self
+    }
+
+    /// Sets the [`Matcher`] that filters the options of the
+    /// [`SearchablePickList`]. Defaults to [`Fuzzy`].
+    pub fn matcher(mut self, matcher: impl Matcher + 'a) -> Self {
+        self.matcher = Box::new(matcher);
+        self
+    }
+
+    /// Sets the placeholder of the [`SearchablePickList`].
+    pub fn placeholder(mut self, placeholder: impl Into<String>) -> Self {
+        self.placeholder = Some(placeholder.into());
+        self
+    }
+
+    /// Sets the width of the [`SearchablePickList`].
+    pub fn width(mut self, width: Length) -> Self {
+        self.width = width;
+        self
+    }
+
+    /// Sets the [`Padding`] of the [`SearchablePickList`].
+    pub fn padding<P: Into<Padding>>(mut self, padding: P) -> Self {
+        self.padding = padding.into();
+        self
+    }
+
+    /// Sets the text size of the [`SearchablePickList`].
+    pub fn text_size(mut self, size: u16) -> Self {
+        self.text_size = Some(size);
+        self
+    }
+
+    /// Sets the font of the [`SearchablePickList`].
+    pub fn font(mut self, font: Renderer::Font) -> Self {
+        self.font = font;
+        self
+    }
+
+    /// Sets the style of the [`SearchablePickList`].
+    pub fn style(mut self, style: impl Into<<Renderer::Theme as StyleSheet>::Style>) -> Self {
+        self.style_sheet = style.into();
+        self
+    }
+
+    /// Sets the style of the text input of the [`SearchablePickList`].
+    pub fn text_style(
+        mut self,
+        style: impl Into<<Renderer::Theme as text_input::StyleSheet>::Style>,
+    ) -> Self {
+        self.text_style_sheet = style.into();
+        self
+    }
+
+    fn filter(&self, state: &mut State<T>) {
+        state.options = matcher::filter(&self.matcher.as_ref(), &state.query, &self.options);
+    }
+}
+
+impl<'a, T: 'static, Message, Renderer> Widget<Message, Renderer>
+    for SearchablePickList<'a, T, Message, Renderer>
+where
+    T: Clone + ToString + Eq,
+    [T]: ToOwned<Owned = Vec<T>>,
+    Message: 'static + Clone,
+    Renderer: text::Renderer + 'a,
+    Renderer::Theme: StyleSheet
+        + scrollable::StyleSheet
+        + menu::StyleSheet
+        + container::StyleSheet
+        + text_input::StyleSheet,
+    <Renderer::Theme as menu::StyleSheet>::Style: From<<Renderer::Theme as StyleSheet>::Style>,
+{
+    fn tag(&self) -> tree::Tag {
+        tree::Tag::of::<State<T>>()
+    }
+
+    fn state(&self) -> tree::State {
+        let mut state = State {
+            pick_list: crate::State::new(),
+            query: String::new(),
+            options: Vec::new(),
+        };
+
+        self.filter(&mut state);
+
+        tree::State::new(state)
+    }
+
+    fn diff(&self, tree: &mut Tree) {
+        self.filter(tree.state.downcast_mut::<State<T>>());
+    }
+
+    fn width(&self) -> Length {
+        self.width
+    }
+
+    fn height(&self) -> Length {
+        Length::Shrink
+    }
+
+    fn layout(&self, renderer: &Renderer, limits: &layout::Limits) -> layout::Node {
+        crate::layout(
+            renderer,
+            limits,
+            self.width,
+            self.padding,
+            self.text_size,
+            &self.font,
+            self.placeholder.as_deref(),
+            None,
+            &self.options,
+        )
+    }
+
+    fn operate(&self, tree: &mut Tree, layout: Layout<'_>, operation: &mut dyn Operation<Message>) {
+        let state = tree.state.downcast_mut::<State<T>>();
+        let mut query = None;
+
+        crate::operate::<T, Message, Renderer>(
+            &mut state.pick_list,
+            self.id.as_ref(),
+            layout,
+            operation,
+            self.selected.as_ref(),
+            &state.options,
+            &mut |new_query| query = Some(new_query),
+        );
+
+        if let Some(query) = query {
+            state.query = query;
+            self.filter(state);
+        }
+    }
+
+    fn on_event(
+        &mut self,
+        tree: &mut Tree,
+        event: Event,
+        layout: Layout<'_>,
+        cursor_position: Point,
+        renderer: &Renderer,
+        clipboard: &mut dyn Clipboard,
+        shell: &mut Shell<'_, Message>,
+    ) -> event::Status {
+        let state = tree.state.downcast_mut::<State<T>>();
+        let was_open = state.pick_list.is_open;
+
+        let mut messages = Vec::new();
+        let mut inner = Shell::new(&mut messages);
+        let mut value = Value::new(&state.query);
+
+        let status = crate::update(
+            event,
+            layout,
+            cursor_position,
+            &mut inner,
+            &Internal::Selected,
+            self.selected.as_ref(),
+            &state.options,
+            || &mut state.pick_list,
+            renderer,
+            clipboard,
+            &mut value,
+            self.text_size,
+            self.padding,
+            &self.font,
+            &Internal::Query,
+            &crate::KeyBindings::default(),
+            crate::UpdateOptions::default(),
+        );
+
+        if inner.is_layout_invalid() {
+            shell.invalidate_layout();
+        }
+
+        if inner.are_widgets_invalid() {
+            shell.invalidate_widgets();
+        }
+
+        let mut query = None;
+
+        for message in messages {
+            match message {
+                Internal::Query(new_query) => query = Some(new_query),
+                Internal::Selected(option) => shell.publish((self.on_selected)(option)),
+            }
+        }
+
+        if was_open && !state.pick_list.is_open {
+            query = Some(String::new());
+        }
+
+        if let Some(query) = query {
+            state.query = query;
+            self.filter(state);
+
+            state.pick_list.hovered_option = (!state.options.is_empty()).then_some(0);
+            shell.invalidate_layout();
+        }
+
+        status
+    }
+
+    fn mouse_interaction(
+        &self,
+        _state: &Tree,
+        layout: Layout<'_>,
+        cursor_position: Point,
+        _viewport: &Rectangle,
+        renderer: &Renderer,
+    ) -> mouse::Interaction {
+        crate::mouse_interaction(
+            layout,
+            cursor_position,
+            renderer,
+            self.padding,
+            self.text_size,
+            false,
+            true,
+        )
+    }
+
+    fn draw(
+        &self,
+        tree: &Tree,
+        renderer: &mut Renderer,
+        theme: &Renderer::Theme,
+        _style: &renderer::Style,
+        layout: Layout<'_>,
+        cursor_position: Point,
+        _viewport: &Rectangle,
+    ) {
+        let state = tree.state.downcast_ref::<State<T>>();
+
+        crate::draw(
+            renderer,
+            layout,
+            cursor_position,
+            &state.pick_list,
+            &Value::new(&state.query),
+            self.padding,
+            self.text_size,
+            &self.font,
+            self.placeholder.as_deref(),
+            self.selected.as_ref(),
+            false,
+            &crate::Handle::default(),
+            None,
+            true,
+            self.matcher.error(&state.query).is_some(),
+            &self.style_sheet,
+            &self.text_style_sheet,
+            theme,
+        )
+    }
+
+    fn overlay<'b>(
+        &'b self,
+        tree: &'b mut Tree,
+        layout: Layout<'_>,
+        renderer: &Renderer,
+    ) -> Option<overlay::Element<'b, Message, Renderer>> {
+        let state = tree.state.downcast_mut::<State<T>>();
+
+        crate::overlay(
+            layout,
+            renderer,
+            &mut state.pick_list,
+            None,
+            Length::Shrink,
+            None,
+            crate::Placement::default(),
+            alignment::Horizontal::Left,
+            self.padding,
+            self.text_size,
+            self.font.clone(),
+            &state.options,
+            None,
+            Some(Box::new(|label: &str| {
+                self.matcher.highlights(&state.query, label)
+            })),
+            None,
+            self.style_sheet.clone(),
+        )
+    }
+}
+
+impl<'a, T: 'static, Message, Renderer> From<SearchablePickList<'a, T, Message, Renderer>>
+    for Element<'a, Message, Renderer>
+where
+    T: Clone + ToString + Eq,
+    [T]: ToOwned<Owned = Vec<T>>,
+    Message: 'static + Clone,
+    Renderer: text::Renderer + 'a,
+    Renderer::Theme: StyleSheet
+        + scrollable::StyleSheet
+        + menu::StyleSheet
+        + container::StyleSheet
+        + text_input::StyleSheet,
+    <Renderer::Theme as menu::StyleSheet>::Style: From<<Renderer::Theme as StyleSheet>::Style>,
+{
+    fn from(pick_list: SearchablePickList<'a, T, Message, Renderer>) -> Self {
+        Element::new(pick_list)
+    }
+}
@@ -0,0 +1,290 @@
+//! Match queries with typos against labels.
+use crate::matcher::{Matcher, Substring};
+use crate::normalize::Folded;
+
+use std::ops::Range;
+
+/// A [`Matcher`] that tolerates typos in the query.
+///
+/// Queries matched by another [`Matcher`] are ranked first. Otherwise, every
+/// term of the query must match a word of the label, or the start of it,
+/// within a number of edits that grows with the length of the term: none for
+/// up to 3 characters, one for up to 7 and two for longer terms. Inserting,
+/// deleting, replacing or swapping two adjacent characters are single edits,
+/// so `recieve` matches `receive`.
+///
+/// Created with [`Matcher::typo_tolerant`].
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct Typo<M = Substring> {
+    matcher: M,
+    max_edits: Option<usize>,
+}
+
+impl<M: Matcher> Typo<M> {
+    /// Creates a new [`Typo`] matcher that ranks the matches of the given
+    /// [`Matcher`] above the ones with typos.
+    pub fn new(matcher: M) -> Self {
+        Self {
+            matcher,
+            max_edits: None,
+        }
+    }
+
+    /// Caps the number of edits allowed for every term of the query.
+    pub fn max_edits(mut self, max_edits: usize) -> Self {
+        self.max_edits = Some(max_edits);
+        self
+    }
+
+    fn allowed_edits(&self, term: &[char]) -> usize {
+        let edits = match term.len() {
+            0..=3 => 0,
+            4..=7 => 1,
+            _ => 2,
+        };
+
+        self.max_edits
+            .map_or(edits, |max_edits| edits.min(max_edits))
+    }
+
+    /// Returns the least number of edits and the byte range of the closest
+    /// word of the label, for every term of the query.
+    fn corrections(&self, query: &str, label: &Folded) -> Option<Vec<(usize, Range<usize>)>> {
+        let words = words(label.as_str());
+
+        query
+            .to_lowercase()
+            .split_whitespace()
+            .map(|term| {
+                let term: Vec<char> = term.chars().collect();
+                let allowed = self.allowed_edits(&term);
+
+                words
+                    .iter()
+                    .filter_map(|(range, word)| {
+                        let edits = distance(&term, word, allowed)?;
+
+                        Some((edits, range.clone()))
+                    })
+                    .min_by_key(|(edits, _)| *edits)
+            })
+            .collect()
+    }
+}
+
+impl<M: Matcher> Matcher for Typo<M> {
+    fn score(&self, query: &str, label: &str) -> Option<f32> {
+        if let Some(score) = self.matcher.score(query, label) {
+            // Corrected matches score below `1.0`.
+            return Some(1.0 + score);
+        }
+
+        let corrections = self.corrections(query, &Folded::lowercase(label))?;
+
+        if corrections.is_empty() {
+            return Some(0.0);
+        }
+
+        let edits: usize = corrections.iter().map(|(edits, _)| edits).sum();
+
+        Some(1.0 / (2.0 + edits as f32))
+    }
+
+    fn highlights(&self, query: &str, label: &str) -> Vec<Range<usize>> {
+        if self.matcher.score(query, label).is_some() {
+            return self.matcher.highlights(query, label);
+        }
+
+        let label = Folded::lowercase(label);
+
+        match self.corrections(query, &label) {
+            Some(corrections) => label.origins(corrections.into_iter().map(|(_, range)| range)),
+            None => Vec::new(),
+        }
+    }
+
+    fn error(&self, query: &str) -> Option<String> {
+        self.matcher.error(query)
+    }
+}
+
+/// Splits a text into its alphanumeric words and their byte ranges.
+fn words(text: &str) -> Vec<(Range<usize>, Vec<char>)> {
+    let mut words = Vec::new();
+    let mut current: Option<(usize, Vec<char>)> = None;
+
+    for (index, c) in text.char_indices() {
+        match (&mut current, c.is_alphanumeric()) {
+            (Some((_, word)), true) => word.push(c),
+            (None, true) => current = Some((index, vec![c])),
+            (Some(_), false) => {
+                if let Some((start, word)) = current.take() {
+                    words.push((start..index, word));
+                }
+            }
+            (None, false) => {}
+        }
+    }
+
+    if let Some((start, word)) = current {
+        words.push((start..text.len(), word));
+    }
+
+    words
+}
+
+/// Returns the least number of edits between the term and the word, or any
+/// prefix of the word, if it does not exceed `allowed`.
+///
+/// Edits are insertions, deletions, substitutions and transpositions of
+/// adjacent characters.
+fn distance(term: &[char], word: &[char], allowed: usize) -> Option<usize> {
+    if word.len() + allowed < term.len() {
+        return None;
+    }
+
+    // Prefixes longer than the term plus the allowed edits cannot match.
+    let word = &word[..word.len().min(term.len() + allowed)];
+
+    let mut previous: Vec<usize> = Vec::new();
+    let mut current: Vec<usize> = (0..=word.len()).collect();
+
+    for (i, &t) in term.iter().enumerate() {
+        let before = std::mem::replace(&mut previous, current.clone());
+        current[0] = i + 1;
+
+        for (j, &w) in word.iter().enumerate() {
+            let substitution = previous[j] + usize::from(t != w);
+            let mut edits = substitution.min(previous[j + 1] + 1).min(current[j] + 1);
+
+            if i > 0 && j > 0 && t == word[j - 1] && term[i - 1] == w {
+                edits = edits.min(before[j - 1] + 1);
+            }
+
+            current[j + 1] = edits;
+        }
+
+        if current.iter().all(|edits| *edits > allowed) {
+            return None;
+        }
+    }
+
+    // The last row holds the distances to every prefix of the word.
+    current.into_iter().min().filter(|edits| *edits <= allowed)
+}
+
+#[cfg(test)]
+#[allow(clippy::single_range_in_vec_init)]
+mod tests {
+    use super::*;
+    use crate::matcher::{self, Fuzzy};
+
+    fn chars(text: &str) -> Vec<char> {
+        text.chars().collect()
+    }
+
+    fn edits(term: &str, word: &str, allowed: usize) -> Option<usize> {
+        distance(&chars(term), &chars(word), allowed)
+    }
+
+    #[test]
+    fn counts_transpositions_as_one_edit() {
+        assert_eq!(edits("recieve", "receive", 1), Some(1));
+        assert_eq!(edits("recieve", "receive", 0), None);
+    }
+
+    #[test]
+    fn counts_single_edits() {
+        assert_eq!(edits("receive", "receive", 0), Some(0));
+        assert_eq!(edits("recive", "receive", 1), Some(1));
+        assert_eq!(edits("receeive", "receive", 1), Some(1));
+        assert_eq!(edits("raceive", "receive", 1), Some(1));
+    }
+
+    #[test]
+    fn matches_prefixes() {
+        assert_eq!(edits("rece", "receive", 0), Some(0));
+        assert_eq!(edits("recv", "receive", 1), Some(1));
+        assert_eq!(edits("rcei", "receive", 1), Some(1));
+    }
+
+    #[test]
+    fn exceeds_allowed_edits() {
+        assert_eq!(edits("raciive", "receive", 1), None);
+        assert_eq!(edits("wxyz", "abcd", 2), None);
+        assert_eq!(edits("receiver", "rec", 2), None);
+    }
+
+    #[test]
+    fn allows_edits_by_term_length() {
+        let typo = Typo::new(Substring);
+
+        assert_eq!(typo.allowed_edits(&chars("abc")), 0);
+        assert_eq!(typo.allowed_edits(&chars("abcd")), 1);
+        assert_eq!(typo.allowed_edits(&chars("abcdefg")), 1);
+        assert_eq!(typo.allowed_edits(&chars("abcdefgh")), 2);
+
+        assert_eq!(typo.max_edits(1).allowed_edits(&chars("abcdefgh")), 1);
+    }
+
+    #[test]
+    fn matches_within_allowed_edits() {
+        let typo = Substring.typo_tolerant();
+
+        // 3 characters, no edits.
+        assert_eq!(typo.score("teh", "The end"), None);
+        // 4 characters, one edit.
+        assert!(typo.score("thme", "Dark theme").is_some());
+        assert_eq!(typo.score("txmx", "Dark theme"), None);
+        // 7 characters, one edit.
+        assert!(typo.score("recieve", "Receive mail").is_some());
+        assert_eq!(typo.score("raciive", "Receive mail"), None);
+        // 8 characters, two edits.
+        assert!(typo.score("recievar", "Mail receiver").is_some());
+        assert_eq!(typo.score("racievar", "Mail receiver"), None);
+    }
+
+    #[test]
+    fn matches_every_term() {
+        let typo = Substring.typo_tolerant();
+
+        assert!(typo.score("recieve mial", "Receive mail").is_some());
+        assert_eq!(typo.score("recieve post", "Receive mail"), None);
+    }
+
+    #[test]
+    fn ranks_exact_matches_above_corrected_ones() {
+        let typo = Fuzzy.typo_tolerant();
+
+        let exact = typo.score("receive", "Receive").unwrap();
+        let corrected = typo.score("recieve", "Receive").unwrap();
+
+        assert!(exact > corrected);
+        assert_eq!(
+            matcher::filter(&typo, "recieve", &["Receive", "Deceive", "Recieve"]),
+            ["Recieve", "Receive"]
+        );
+    }
+
+    #[test]
+    fn ranks_fewer_edits_first() {
+        let typo = Substring.typo_tolerant();
+
+        let one = typo.score("recievar", "receivar").unwrap();
+        let two = typo.score("recievar", "receiver").unwrap();
+
+        assert!(one > two);
+    }
+
+    #[test]
+    fn highlights_corrected_words() {
+        let typo = Substring.typo_tolerant();
+
+        assert_eq!(typo.highlights("recieve", "Receive mail"), [0..7]);
+        assert_eq!(
+            typo.highlights("mial recieve", "Receive mail"),
+            [0..7, 8..12]
+        );
+        assert_eq!(typo.highlights("rec", "Receive mail"), [0..3]);
+    }
+}